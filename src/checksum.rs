@@ -0,0 +1,187 @@
+use std::fmt;
+
+use crate::offsets;
+
+// Every checksummed area of a Gen 1 save. The main block covers the player's game data,
+// the bank checksums cover all six boxes stored in SRAM bank 2 or 3 and each box also
+// has its own checksum stored right after its bank's whole checksum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChecksumRegion {
+    Main,
+    Bank2,
+    Bank3,
+    Box(usize),
+}
+
+// What the game does on load when a region's stored checksum does not match its data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoadEffect {
+    // Checksum matches, the region loads normally.
+    None,
+    // The game shows "The file data is destroyed!" and starts as if there was no save.
+    FileDestroyed,
+    // The game never verifies bank or box checksums, so the boxes load as stored even
+    // though their contents are probably damaged.
+    BoxesUnverified,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RegionChecksum {
+    pub region: ChecksumRegion,
+    pub stored: u8,
+    pub computed: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumReport {
+    pub regions: Vec<RegionChecksum>,
+}
+
+impl ChecksumRegion {
+
+    // All regions in the order they are laid out in SRAM: main, bank 2, boxes 1-6, bank 3, boxes 7-12.
+    pub fn all() -> Vec<ChecksumRegion> {
+        let mut regions = vec![ChecksumRegion::Main, ChecksumRegion::Bank2];
+        regions.extend((1..=offsets::BOXES_PER_BANK).map(ChecksumRegion::Box));
+        regions.push(ChecksumRegion::Bank3);
+        regions.extend((offsets::BOXES_PER_BANK + 1..=offsets::NUM_POKEMON_BOXES).map(ChecksumRegion::Box));
+        regions
+    }
+
+    // First byte covered by the checksum.
+    pub fn start(&self) -> usize {
+        match self {
+            ChecksumRegion::Main => offsets::CHECKSUM_START,
+            ChecksumRegion::Bank2 => offsets::BOX_1_DATA_OFFSET,
+            ChecksumRegion::Bank3 => offsets::BOX_7_DATA_OFFSET,
+            ChecksumRegion::Box(box_number) => {
+                if *box_number <= offsets::BOXES_PER_BANK {
+                    offsets::BOX_1_DATA_OFFSET + offsets::BOX_NEXT_BOX * (box_number - 1)
+                }
+                else {
+                    offsets::BOX_7_DATA_OFFSET + offsets::BOX_NEXT_BOX * (box_number - 7)
+                }
+            }
+        }
+    }
+
+    // Last byte covered by the checksum (inclusive).
+    pub fn end(&self) -> usize {
+        match self {
+            ChecksumRegion::Main => offsets::CHECKSUM_END,
+            ChecksumRegion::Bank2 => offsets::BANK2_WHOLE_CHECKSUM - 1,
+            ChecksumRegion::Bank3 => offsets::BANK3_WHOLE_CHECKSUM - 1,
+            ChecksumRegion::Box(_) => self.start() + offsets::BOX_NEXT_BOX - 1,
+        }
+    }
+
+    // Offset of the byte the checksum is stored in.
+    pub fn checksum_offset(&self) -> usize {
+        match self {
+            ChecksumRegion::Main => offsets::CHECKSUM_OFFSET,
+            ChecksumRegion::Bank2 => offsets::BANK2_WHOLE_CHECKSUM,
+            ChecksumRegion::Bank3 => offsets::BANK3_WHOLE_CHECKSUM,
+            ChecksumRegion::Box(box_number) => {
+                if *box_number <= offsets::BOXES_PER_BANK {
+                    offsets::BANK2_BOX_CHECKSUMS + (box_number - 1)
+                }
+                else {
+                    offsets::BANK3_BOX_CHECKSUMS + (box_number - 7)
+                }
+            }
+        }
+    }
+
+    // What the game does when this region's checksum is wrong.
+    pub fn mismatch_effect(&self) -> LoadEffect {
+        match self {
+            ChecksumRegion::Main => LoadEffect::FileDestroyed,
+            _ => LoadEffect::BoxesUnverified,
+        }
+    }
+}
+
+impl RegionChecksum {
+    pub fn is_valid(&self) -> bool {
+        self.stored == self.computed
+    }
+
+    pub fn load_effect(&self) -> LoadEffect {
+        if self.is_valid() {
+            LoadEffect::None
+        }
+        else {
+            self.region.mismatch_effect()
+        }
+    }
+}
+
+impl ChecksumReport {
+
+    // True when every region's stored checksum matches its data.
+    pub fn is_valid(&self) -> bool {
+        self.regions.iter().all(|r| r.is_valid())
+    }
+
+    // True when the game will accept the file. Only the main checksum is checked on load.
+    pub fn loads_in_game(&self) -> bool {
+        self.region(ChecksumRegion::Main).is_some_and(|r| r.is_valid())
+    }
+
+    pub fn region(&self, region: ChecksumRegion) -> Option<&RegionChecksum> {
+        self.regions.iter().find(|r| r.region == region)
+    }
+
+    pub fn mismatches(&self) -> Vec<&RegionChecksum> {
+        self.regions.iter().filter(|r| !r.is_valid()).collect()
+    }
+}
+
+impl fmt::Display for ChecksumRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumRegion::Main => write!(f, "Main data"),
+            ChecksumRegion::Bank2 => write!(f, "Bank 2 (boxes 1-6)"),
+            ChecksumRegion::Bank3 => write!(f, "Bank 3 (boxes 7-12)"),
+            ChecksumRegion::Box(box_number) => write!(f, "Box {box_number}"),
+        }
+    }
+}
+
+impl fmt::Display for LoadEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            LoadEffect::None => "Loads normally",
+            LoadEffect::FileDestroyed => "File data destroyed, game refuses the save",
+            LoadEffect::BoxesUnverified => "Not verified by the game, box data may be damaged",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+impl fmt::Display for RegionChecksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.is_valid() { "OK" } else { "MISMATCH" };
+        write!(
+            f,
+            "{} [0x{:04X}-0x{:04X}] stored: 0x{:02X} computed: 0x{:02X} {} - {}",
+            self.region,
+            self.region.start(),
+            self.region.end(),
+            self.stored,
+            self.computed,
+            status,
+            self.load_effect()
+        )
+    }
+}
+
+impl fmt::Display for ChecksumReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for region in &self.regions {
+            writeln!(f, "{region}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::{ChucksumError, Result};
+use crate::items;
+use crate::offsets;
+use crate::pokemon::{Pokemon, Status};
+use crate::save_file::{Badges, ItemStorage, PokemonError, PokemonLocation, PokemonStorage, SaveFile, StoredPokemon};
+
+// One field of a pokemon that differs between two saves, already formatted for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub from: String,
+    pub to: String,
+}
+
+// A single semantic difference between two saves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    PlayerName { from: String, to: String },
+    RivalName { from: String, to: String },
+    Money { from: u32, to: u32 },
+    BadgesGained(Badges),
+    BadgesLost(Badges),
+    ItemAdded { storage: ItemStorage, item_id: u8, qty: u32 },
+    ItemRemoved { storage: ItemStorage, item_id: u8, qty: u32 },
+    ItemQuantity { storage: ItemStorage, item_id: u8, from: u32, to: u32 },
+    PokemonAdded { location: PokemonLocation, species_id: u8, nickname: String },
+    PokemonRemoved { location: PokemonLocation, species_id: u8, nickname: String },
+    PokemonMoved { from: PokemonLocation, to: PokemonLocation, species_id: u8, nickname: String },
+    // Field changes on a pokemon present in both saves, reported at its location in the newer save.
+    PokemonEdited { location: PokemonLocation, species_id: u8, changes: Vec<FieldChange> },
+    // The party or box has a corrupt count in one of the saves, so its pokemon were not compared.
+    PokemonListUnreadable { storage: PokemonStorage },
+}
+
+// Everything that changed going from `old` to `new`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SaveDiff {
+
+    pub fn between(old: &SaveFile, new: &SaveFile) -> Result<SaveDiff> {
+        let mut changes = Vec::new();
+
+        let (old_name, new_name) = (old.get_player_name()?, new.get_player_name()?);
+        if old_name != new_name {
+            changes.push(Change::PlayerName { from: old_name, to: new_name });
+        }
+        let (old_rival, new_rival) = (old.get_rival_name()?, new.get_rival_name()?);
+        if old_rival != new_rival {
+            changes.push(Change::RivalName { from: old_rival, to: new_rival });
+        }
+        let (old_money, new_money) = (old.get_money()?, new.get_money()?);
+        if old_money != new_money {
+            changes.push(Change::Money { from: old_money, to: new_money });
+        }
+
+        let (old_badges, new_badges) = (old.get_badges()?, new.get_badges()?);
+        if !(new_badges - old_badges).is_empty() {
+            changes.push(Change::BadgesGained(new_badges - old_badges));
+        }
+        if !(old_badges - new_badges).is_empty() {
+            changes.push(Change::BadgesLost(old_badges - new_badges));
+        }
+
+        for storage in [ItemStorage::Bag, ItemStorage::PcBox] {
+            diff_items(storage, &old.get_items(storage)?, &new.get_items(storage)?, &mut changes);
+        }
+
+        let (mut old_pokemon, mut new_pokemon) = (Vec::new(), Vec::new());
+        let storages = std::iter::once(PokemonStorage::Party)
+            .chain((1..=offsets::NUM_POKEMON_BOXES).map(PokemonStorage::PcBox));
+        for storage in storages {
+            match (readable_pokemon(old, storage)?, readable_pokemon(new, storage)?) {
+                (Some(a), Some(b)) => {
+                    old_pokemon.extend(a);
+                    new_pokemon.extend(b);
+                }
+                _ => changes.push(Change::PokemonListUnreadable { storage }),
+            }
+        }
+        diff_pokemon(old_pokemon, new_pokemon, &mut changes);
+
+        Ok(SaveDiff { changes })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Change {
+    // Money gained (positive) or spent (negative). Zero for every other change.
+    pub fn money_delta(&self) -> i64 {
+        match self {
+            Change::Money { from, to } => *to as i64 - *from as i64,
+            _ => 0,
+        }
+    }
+}
+
+fn diff_items(storage: ItemStorage, old: &[(u8, u8)], new: &[(u8, u8)], changes: &mut Vec<Change>) {
+    // The same item can take more than one slot, so compare total quantities per item
+    let totals = |list: &[(u8, u8)]| {
+        let mut totals: BTreeMap<u8, u32> = BTreeMap::new();
+        for (item_id, qty) in list {
+            *totals.entry(*item_id).or_default() += *qty as u32;
+        }
+        totals
+    };
+    let (old, new) = (totals(old), totals(new));
+
+    for (item_id, old_qty) in &old {
+        match new.get(item_id) {
+            None => changes.push(Change::ItemRemoved { storage, item_id: *item_id, qty: *old_qty }),
+            Some(new_qty) if new_qty != old_qty => {
+                changes.push(Change::ItemQuantity { storage, item_id: *item_id, from: *old_qty, to: *new_qty });
+            }
+            Some(_) => (),
+        }
+    }
+    for (item_id, new_qty) in &new {
+        if !old.contains_key(item_id) {
+            changes.push(Change::ItemAdded { storage, item_id: *item_id, qty: *new_qty });
+        }
+    }
+}
+
+// The pokemon in a party or box, or None if its count byte is over capacity.
+fn readable_pokemon(save: &SaveFile, storage: PokemonStorage) -> Result<Option<Vec<StoredPokemon>>> {
+    match save.get_stored_pokemon(storage) {
+        Ok(list) => Ok(Some(list)),
+        Err(ChucksumError::Pokemon(PokemonError::InvalidData)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Gen 1 pokemon have no unique id. The original trainer and the DVs never change once a pokemon
+// is caught, so together with the species they are the closest thing to one.
+fn same_pokemon(a: &StoredPokemon, b: &StoredPokemon) -> bool {
+    a.pokemon.species_id == b.pokemon.species_id
+        && a.pokemon.ot_id == b.pokemon.ot_id
+        && a.ot_name == b.ot_name
+        && (a.pokemon.attack_iv, a.pokemon.defense_iv, a.pokemon.speed_iv, a.pokemon.special_iv)
+            == (b.pokemon.attack_iv, b.pokemon.defense_iv, b.pokemon.speed_iv, b.pokemon.special_iv)
+}
+
+fn diff_pokemon(old: Vec<StoredPokemon>, new: Vec<StoredPokemon>, changes: &mut Vec<Change>) {
+    let mut old: Vec<Option<StoredPokemon>> = old.into_iter().map(Some).collect();
+    let mut new: Vec<Option<StoredPokemon>> = new.into_iter().map(Some).collect();
+    let mut pairs = Vec::new();
+
+    // Pair up pokemon from most to least certain: same pokemon in the same slot, same
+    // pokemon somewhere else, then whatever is in the same slot with the same species
+    // and trainer (an edit that touched the DVs).
+    let passes: [fn(&StoredPokemon, &StoredPokemon) -> bool; 3] = [
+        |a, b| a.location == b.location && same_pokemon(a, b),
+        same_pokemon,
+        |a, b| a.location == b.location && a.pokemon.species_id == b.pokemon.species_id && a.pokemon.ot_id == b.pokemon.ot_id,
+    ];
+    for matches in passes {
+        for old_slot in old.iter_mut() {
+            let Some(a) = old_slot else { continue };
+            if let Some(new_slot) = new.iter_mut().find(|n| n.as_ref().is_some_and(|b| matches(a, b))) {
+                pairs.push((old_slot.take().unwrap(), new_slot.take().unwrap()));
+            }
+        }
+    }
+
+    for (a, b) in pairs {
+        if a.location != b.location {
+            changes.push(Change::PokemonMoved {
+                from: a.location,
+                to: b.location,
+                species_id: b.pokemon.species_id,
+                nickname: b.nickname.clone(),
+            });
+        }
+        let field_changes = field_changes(&a, &b);
+        if !field_changes.is_empty() {
+            changes.push(Change::PokemonEdited { location: b.location, species_id: b.pokemon.species_id, changes: field_changes });
+        }
+    }
+
+    for a in old.into_iter().flatten() {
+        changes.push(Change::PokemonRemoved { location: a.location, species_id: a.pokemon.species_id, nickname: a.nickname });
+    }
+    for b in new.into_iter().flatten() {
+        changes.push(Change::PokemonAdded { location: b.location, species_id: b.pokemon.species_id, nickname: b.nickname });
+    }
+}
+
+fn field_changes(a: &StoredPokemon, b: &StoredPokemon) -> Vec<FieldChange> {
+    let (p, q) = (&a.pokemon, &b.pokemon);
+    let species = |id: u8| Pokemon::get_species_name(id).to_string();
+    let move_name = |id: u8| Pokemon::get_move_name(id).to_string();
+    let type_name = |id: u8| Pokemon::get_type_name(id).to_string();
+    let status = |s: u8| Status::from_bits_retain(s).to_string();
+
+    let mut fields = vec![
+        ("Nickname", a.nickname.clone(), b.nickname.clone()),
+        ("OT name", a.ot_name.clone(), b.ot_name.clone()),
+        ("Species", species(p.species_id), species(q.species_id)),
+        ("Level", p.level.to_string(), q.level.to_string()),
+        ("Current HP", p.current_hp.to_string(), q.current_hp.to_string()),
+        ("Status", status(p.status), status(q.status)),
+        ("Type 1", type_name(p.pkmn_type_1), type_name(q.pkmn_type_1)),
+        ("Type 2", type_name(p.pkmn_type_2), type_name(q.pkmn_type_2)),
+        ("Catch rate", p.catch_rate.to_string(), q.catch_rate.to_string()),
+        ("Move 1", move_name(p.move_index1), move_name(q.move_index1)),
+        ("Move 2", move_name(p.move_index2), move_name(q.move_index2)),
+        ("Move 3", move_name(p.move_index3), move_name(q.move_index3)),
+        ("Move 4", move_name(p.move_index4), move_name(q.move_index4)),
+        ("OT ID", p.ot_id.to_string(), q.ot_id.to_string()),
+        ("Experience", p.experience_pts.to_string(), q.experience_pts.to_string()),
+        ("HP stat exp", p.hp_stat_exp.to_string(), q.hp_stat_exp.to_string()),
+        ("Attack stat exp", p.attack_stat_exp.to_string(), q.attack_stat_exp.to_string()),
+        ("Defense stat exp", p.defense_stat_exp.to_string(), q.defense_stat_exp.to_string()),
+        ("Speed stat exp", p.speed_stat_exp.to_string(), q.speed_stat_exp.to_string()),
+        ("Special stat exp", p.special_stat_exp.to_string(), q.special_stat_exp.to_string()),
+        ("Attack DV", p.attack_iv.to_string(), q.attack_iv.to_string()),
+        ("Defense DV", p.defense_iv.to_string(), q.defense_iv.to_string()),
+        ("Speed DV", p.speed_iv.to_string(), q.speed_iv.to_string()),
+        ("Special DV", p.special_iv.to_string(), q.special_iv.to_string()),
+    ];
+
+    // Boxed pokemon have no stored stats, so only compare them when both copies are in the party
+    if a.location.storage == PokemonStorage::Party && b.location.storage == PokemonStorage::Party {
+        fields.extend([
+            ("Max HP", p.max_hp.to_string(), q.max_hp.to_string()),
+            ("Attack", p.attack.to_string(), q.attack.to_string()),
+            ("Defense", p.defense.to_string(), q.defense.to_string()),
+            ("Speed", p.speed.to_string(), q.speed.to_string()),
+            ("Special", p.special.to_string(), q.special.to_string()),
+        ]);
+    }
+
+    fields.into_iter()
+        .filter(|(_, from, to)| from != to)
+        .map(|(field, from, to)| FieldChange { field, from, to })
+        .collect()
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let species = Pokemon::get_species_name;
+        match self {
+            Change::PlayerName { from, to } => write!(f, "Player name: {from} -> {to}"),
+            Change::RivalName { from, to } => write!(f, "Rival name: {from} -> {to}"),
+            Change::Money { from, to } => write!(f, "Money: ${from} -> ${to} ({:+})", self.money_delta()),
+            Change::BadgesGained(badges) => write!(f, "Badges gained: {badges:?}"),
+            Change::BadgesLost(badges) => write!(f, "Badges lost: {badges:?}"),
+            Change::ItemAdded { storage, item_id, qty } =>
+                write!(f, "{storage}: added {} x{qty}", items::get_item_name(*item_id)),
+            Change::ItemRemoved { storage, item_id, qty } =>
+                write!(f, "{storage}: removed {} x{qty}", items::get_item_name(*item_id)),
+            Change::ItemQuantity { storage, item_id, from, to } =>
+                write!(f, "{storage}: {} x{from} -> x{to}", items::get_item_name(*item_id)),
+            Change::PokemonAdded { location, species_id, nickname } =>
+                write!(f, "{location}: added {} ({nickname})", species(*species_id)),
+            Change::PokemonRemoved { location, species_id, nickname } =>
+                write!(f, "{location}: removed {} ({nickname})", species(*species_id)),
+            Change::PokemonMoved { from, to, species_id, nickname } =>
+                write!(f, "{} ({nickname}) moved from {from} to {to}", species(*species_id)),
+            Change::PokemonEdited { location, species_id, changes } => {
+                write!(f, "{location}: {} edited", species(*species_id))?;
+                for change in changes {
+                    write!(f, "\n    {}: {} -> {}", change.field, change.from, change.to)?;
+                }
+                Ok(())
+            }
+            Change::PokemonListUnreadable { storage } =>
+                write!(f, "{storage}: pokemon not compared, the count byte is corrupt"),
+        }
+    }
+}
+
+impl fmt::Display for SaveDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
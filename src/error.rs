@@ -0,0 +1,73 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::save_file::{BagError, PokemonError};
+
+// Every error the crate can return. Bag and pokemon errors keep their own types so
+// callers that already match on them can keep doing so.
+#[derive(Debug)]
+pub enum ChucksumError {
+    Io(std::io::Error),
+    // An access of `len` bytes starting at `offset` does not fit in the save data.
+    OutOfBounds { offset: usize, len: usize, file_len: usize },
+    InvalidFileSize { len: usize, expected: usize },
+    // The file is the wrong kind of data altogether, such as a blank file or a GBA save.
+    UnrecognizedSave { len: usize, reason: &'static str },
+    // The file being saved over was changed by something else since it was loaded.
+    ModifiedOnDisk { path: PathBuf },
+    // The file being saved over was deleted or moved since it was loaded.
+    MissingOnDisk { path: PathBuf },
+    Bag(BagError),
+    Pokemon(PokemonError),
+}
+
+pub type Result<T> = std::result::Result<T, ChucksumError>;
+
+impl fmt::Display for ChucksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChucksumError::Io(e) => write!(f, "I/O error: {e}"),
+            ChucksumError::OutOfBounds { offset, len, file_len } =>
+                write!(f, "Access of {len} byte(s) at 0x{offset:04X} is outside the {file_len} byte save data"),
+            ChucksumError::InvalidFileSize { len, expected } =>
+                write!(f, "Save file is {len} bytes, expected {expected} bytes"),
+            ChucksumError::UnrecognizedSave { len, reason } =>
+                write!(f, "Not a Gen 1 save ({len} bytes): {reason}"),
+            ChucksumError::ModifiedOnDisk { path } =>
+                write!(f, "{} changed on disk since it was loaded. Save with force to overwrite it", path.display()),
+            ChucksumError::MissingOnDisk { path } =>
+                write!(f, "{} was deleted or moved since it was loaded. Save with force to write it again", path.display()),
+            ChucksumError::Bag(e) => write!(f, "{e}"),
+            ChucksumError::Pokemon(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ChucksumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChucksumError::Io(e) => Some(e),
+            ChucksumError::Bag(e) => Some(e),
+            ChucksumError::Pokemon(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ChucksumError {
+    fn from(e: std::io::Error) -> Self {
+        ChucksumError::Io(e)
+    }
+}
+
+impl From<BagError> for ChucksumError {
+    fn from(e: BagError) -> Self {
+        ChucksumError::Bag(e)
+    }
+}
+
+impl From<PokemonError> for ChucksumError {
+    fn from(e: PokemonError) -> Self {
+        ChucksumError::Pokemon(e)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::species::GrowthRate;
+
+pub const MIN_LEVEL: u8 = 1;
+pub const MAX_LEVEL: u8 = 100;
+
+// Total experience needed to reach `level`, using the game's integer arithmetic.
+// Medium Slow goes negative at level 1 (the game underflows there); that is reported as 0.
+pub fn exp_for_level(growth_rate: GrowthRate, level: u8) -> u32 {
+    let n = level.clamp(MIN_LEVEL, MAX_LEVEL) as i64;
+    let cube = n * n * n;
+
+    let exp = match growth_rate {
+        GrowthRate::MediumFast => cube,
+        GrowthRate::MediumSlow => cube * 6 / 5 - 15 * n * n + 100 * n - 140,
+        GrowthRate::Fast => cube * 4 / 5,
+        GrowthRate::Slow => cube * 5 / 4,
+    };
+    exp.max(0) as u32
+}
+
+// Highest level whose experience requirement `exp` meets.
+pub fn level_for_exp(growth_rate: GrowthRate, exp: u32) -> u8 {
+    (MIN_LEVEL + 1..=MAX_LEVEL)
+        .take_while(|level| exp_for_level(growth_rate, *level) <= exp)
+        .last()
+        .unwrap_or(MIN_LEVEL)
+}
+
+// Experience still needed to reach the next level. 0 at level 100.
+pub fn exp_to_next_level(growth_rate: GrowthRate, exp: u32) -> u32 {
+    let level = level_for_exp(growth_rate, exp);
+    if level == MAX_LEVEL {
+        return 0;
+    }
+    exp_for_level(growth_rate, level + 1) - exp
+}
@@ -0,0 +1,72 @@
+use crate::error::{ChucksumError, Result};
+use crate::offsets;
+
+// Largest footer accepted after the SRAM image. Emulator footers (RTC state and the like)
+// are well under this, anything bigger is probably not a Gen 1 save at all.
+pub const MAX_FOOTER_SIZE: usize = 0x200;
+
+// Sizes flashcarts commonly pad Game Boy SRAM dumps to.
+pub const PADDED_SIZES: [usize; 2] = [0x10000, 0x20000];
+
+// The shape of the file a save was loaded from. SaveFile always works on the bare
+// 32 KiB SRAM image and uses this to write the file back the way it found it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveContainer {
+    // Plain 32 KiB SRAM image (.sav/.srm).
+    Raw,
+    // Flashcart dump padded with `fill` bytes up to `len`.
+    Padded { len: usize, fill: u8 },
+    // SRAM image followed by data appended by an emulator.
+    Footer(Vec<u8>),
+}
+
+impl SaveContainer {
+
+    // Works out what kind of file `data` is, rejecting anything that can't be a Gen 1 save.
+    pub fn detect(data: &[u8]) -> Result<SaveContainer> {
+        let len = data.len();
+
+        if len == 0 {
+            return Err(ChucksumError::UnrecognizedSave { len, reason: "file is empty" });
+        }
+        if len < offsets::SAVE_FILE_SIZE {
+            return Err(ChucksumError::InvalidFileSize { len, expected: offsets::SAVE_FILE_SIZE });
+        }
+
+        let (sram, extra) = data.split_at(offsets::SAVE_FILE_SIZE);
+        if sram.iter().all(|b| *b == sram[0]) {
+            return Err(ChucksumError::UnrecognizedSave { len, reason: "SRAM image is blank" });
+        }
+
+        if extra.is_empty() {
+            return Ok(SaveContainer::Raw);
+        }
+
+        if PADDED_SIZES.contains(&len) {
+            let fill = extra[0];
+            if (fill == 0x00 || fill == 0xFF) && extra.iter().all(|b| *b == fill) {
+                return Ok(SaveContainer::Padded { len, fill });
+            }
+            // A full size image with real data past 32 KiB is a GBA flash save or another cartridge's SRAM.
+            return Err(ChucksumError::UnrecognizedSave { len, reason: "data past 32 KiB, not a Gen 1 save" });
+        }
+
+        if extra.len() <= MAX_FOOTER_SIZE {
+            return Ok(SaveContainer::Footer(extra.to_vec()));
+        }
+
+        Err(ChucksumError::UnrecognizedSave { len, reason: "unsupported file size" })
+    }
+
+    // Rebuilds the original file layout around a 32 KiB SRAM image.
+    pub fn wrap(&self, sram: &[u8]) -> Vec<u8> {
+        let mut output = sram.to_vec();
+
+        match self {
+            SaveContainer::Raw => (),
+            SaveContainer::Padded { len, fill } => output.resize(*len, *fill),
+            SaveContainer::Footer(footer) => output.extend_from_slice(footer),
+        }
+        output
+    }
+}
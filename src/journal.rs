@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+
+use crate::checksum::ChecksumRegion;
+use crate::save_file::{ItemStorage, PokemonLocation, PokemonStorage};
+
+// One write made through SaveFile: the bytes at `offset` before and after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub offset: usize,
+    pub before: Vec<u8>,
+    pub after: Vec<u8>,
+}
+
+impl JournalEntry {
+    pub fn len(&self) -> usize {
+        self.after.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.after.is_empty()
+    }
+}
+
+// A point in the journal that SaveFile::rollback_to() can return to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(usize);
+
+impl Checkpoint {
+    // The save as it was loaded, before any writes.
+    pub const LOADED: Checkpoint = Checkpoint(0);
+}
+
+// Every write made to a save since it was loaded, in order, plus the edits that have been
+// undone and can still be redone. Doing a new write after an undo discards the redo history.
+//
+// Writes are grouped into edits. All the writes made while a group is open (see begin_group())
+// are one edit, any other write is an edit on its own. Undo and redo work an edit at a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+    // Index of the first entry of each edit.
+    edit_starts: Vec<usize>,
+    undone: Vec<Vec<JournalEntry>>,
+    // Nesting depth of begin_group() calls and where the outermost group started.
+    open_groups: usize,
+    group_start: usize,
+}
+
+impl Journal {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Applied writes, oldest first.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Number of edits that can be undone.
+    pub fn edit_count(&self) -> usize {
+        self.edit_starts.len()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.entries.len())
+    }
+
+    pub fn record(&mut self, entry: JournalEntry) {
+        let start = if self.open_groups > 0 { self.group_start } else { self.entries.len() };
+        if self.edit_starts.last() != Some(&start) {
+            self.edit_starts.push(start);
+        }
+        self.entries.push(entry);
+        self.undone.clear();
+    }
+
+    // Makes every write until the matching end_group() part of one edit. Groups can be nested,
+    // only the outermost one counts.
+    pub fn begin_group(&mut self) {
+        if self.open_groups == 0 {
+            self.group_start = self.entries.len();
+        }
+        self.open_groups += 1;
+    }
+
+    pub fn end_group(&mut self) {
+        self.open_groups = self.open_groups.saturating_sub(1);
+    }
+
+    // Takes the most recent edit off the journal and keeps it for redo.
+    // The caller is responsible for restoring the `before` bytes of its writes, newest first.
+    pub fn undo(&mut self) -> Option<&[JournalEntry]> {
+        let start = self.edit_starts.pop()?;
+        let edit = self.entries.split_off(start);
+        self.undone.push(edit);
+        self.undone.last().map(Vec::as_slice)
+    }
+
+    // Puts the most recently undone edit back on the journal.
+    // The caller is responsible for reapplying the `after` bytes of its writes, oldest first.
+    pub fn redo(&mut self) -> Option<&[JournalEntry]> {
+        let edit = self.undone.pop()?;
+        let start = self.entries.len();
+        self.edit_starts.push(start);
+        self.entries.extend(edit);
+        Some(&self.entries[start..])
+    }
+
+    // Removes every write made after `checkpoint`, newest first, without keeping them for redo.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) -> Vec<JournalEntry> {
+        self.undone.clear();
+        let mut rolled_back = self.entries.split_off(checkpoint.0.min(self.entries.len()));
+        self.edit_starts.retain(|start| *start < self.entries.len());
+        rolled_back.reverse();
+        rolled_back
+    }
+
+    // The value every written byte had before the first write to it.
+    pub fn original_bytes(&self) -> BTreeMap<usize, u8> {
+        let mut original = BTreeMap::new();
+        for entry in &self.entries {
+            for (i, byte) in entry.before.iter().enumerate() {
+                original.entry(entry.offset + i).or_insert(*byte);
+            }
+        }
+        original
+    }
+}
+
+// The part of the save a dirty byte belongs to, as reported by SaveFile::dirty_fields().
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SaveField {
+    PlayerName,
+    RivalName,
+    Money,
+    Badges,
+    PlayerId,
+    Items(ItemStorage),
+    CurrentBox,
+    // The count byte or species list of the party or a box.
+    PokemonList(PokemonStorage),
+    // A pokemon's data structure, OT name or nickname.
+    Pokemon(PokemonLocation),
+    Checksum(ChecksumRegion),
+    Other(usize),
+}
+
+impl std::fmt::Display for SaveField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveField::PlayerName => write!(f, "Player name"),
+            SaveField::RivalName => write!(f, "Rival name"),
+            SaveField::Money => write!(f, "Money"),
+            SaveField::Badges => write!(f, "Badges"),
+            SaveField::PlayerId => write!(f, "Player ID"),
+            SaveField::Items(storage) => write!(f, "{storage}"),
+            SaveField::CurrentBox => write!(f, "Current box"),
+            SaveField::PokemonList(storage) => write!(f, "{storage} species list"),
+            SaveField::Pokemon(location) => write!(f, "{location}"),
+            SaveField::Checksum(region) => write!(f, "{region} checksum"),
+            SaveField::Other(offset) => write!(f, "0x{offset:04X}"),
+        }
+    }
+}
@@ -2,4 +2,17 @@ pub mod save_file;
 pub mod textencoding;
 pub mod items;
 pub mod pokemon;
-pub mod offsets;
\ No newline at end of file
+pub mod offsets;
+pub mod checksum;
+pub mod validator;
+pub mod repair;
+pub mod error;
+pub mod format;
+pub mod persist;
+pub mod diff;
+pub mod journal;
+pub mod stats;
+pub mod species;
+pub mod experience;
+pub mod moves;
+pub mod types;
@@ -0,0 +1,327 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::{PokemonType, BUG, DRAGON, ELECTRIC, FIGHTING, FIRE, FLYING, GHOST, GRASS, GROUND, ICE, NORMAL, POISON, PSYCHIC, ROCK, WATER};
+
+use MoveEffect::*;
+
+pub const MAX_PP_UPS: u8 = 3;
+// The most a single PP Up can add, so 40 PP moves go 47/54/61 rather than 48/56/64.
+const MAX_PP_UP_BONUS: u8 = 7;
+
+// Each PP Up adds a fifth of the base PP, rounded down and capped at 7.
+pub fn max_pp(base_pp: u8, pp_ups: u8) -> u8 {
+    base_pp + (base_pp / 5).min(MAX_PP_UP_BONUS) * pp_ups.min(MAX_PP_UPS)
+}
+
+// What a move does besides (or instead of) dealing damage, grouped the way the
+// game's effect handlers group them. Chance-based and guaranteed effects share a category.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MoveEffect {
+    // Plain damage.
+    NoEffect,
+    Sleep,
+    Poison,
+    Burn,
+    Freeze,
+    Paralyze,
+    Confuse,
+    Flinch,
+    // Raises one of the user's stat stages.
+    RaiseStat,
+    // Lowers one of the target's stat stages.
+    LowerStat,
+    // Heals the user by half the damage dealt.
+    Drain,
+    Recoil,
+    // Hits two to five times, or exactly twice.
+    MultiHit,
+    HighCritical,
+    // Charges (or goes underground/up high) on the first turn and attacks on the second.
+    TwoTurn,
+    Recharge,
+    // Thrash and Petal Dance: attacks for several turns, then confuses the user.
+    Rampage,
+    Trap,
+    OneHitKo,
+    // Damage that ignores the damage formula, like Seismic Toss or Super Fang.
+    FixedDamage,
+    Heal,
+    Explode,
+    Priority,
+    // Everything with a one-off handler: Counter, Metronome, Transform and so on.
+    Other,
+}
+
+// The game's data for a move, keyed by the move id stored in save data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MoveInfo {
+    pub id: u8,
+    pub name: &'static str,
+    pub move_type: PokemonType,
+    // Base power used by the damage formula. 0 for status moves and for moves
+    // that work out their damage some other way (FixedDamage, OneHitKo, Counter).
+    pub power: u8,
+    // Accuracy in percent.
+    pub accuracy: u8,
+    // PP with no PP Ups applied.
+    pub pp: u8,
+    pub effect: MoveEffect,
+}
+
+const fn mv(id: u8, name: &'static str, move_type: PokemonType, power: u8, accuracy: u8, pp: u8, effect: MoveEffect) -> MoveInfo {
+    MoveInfo { id, name, move_type, power, accuracy, pp, effect }
+}
+
+// Every move from 1 to 165, in id order.
+static MOVES: [MoveInfo; 165] = [
+    mv(  1, "Pound", NORMAL,  40, 100, 35, NoEffect),
+    mv(  2, "Karate Chop", NORMAL,  50, 100, 25, HighCritical),
+    mv(  3, "Double Slap", NORMAL,  15,  85, 10, MultiHit),
+    mv(  4, "Comet Punch", NORMAL,  18,  85, 15, MultiHit),
+    mv(  5, "Mega Punch", NORMAL,  80,  85, 20, NoEffect),
+    mv(  6, "Pay Day", NORMAL,  40, 100, 20, Other),
+    mv(  7, "Fire Punch", FIRE,  75, 100, 15, Burn),
+    mv(  8, "Ice Punch", ICE,  75, 100, 15, Freeze),
+    mv(  9, "Thunder Punch", ELECTRIC,  75, 100, 15, Paralyze),
+    mv( 10, "Scratch", NORMAL,  40, 100, 35, NoEffect),
+    mv( 11, "Vise Grip", NORMAL,  55, 100, 30, NoEffect),
+    mv( 12, "Guillotine", NORMAL,   0,  30,  5, OneHitKo),
+    mv( 13, "Razor Wind", NORMAL,  80,  75, 10, TwoTurn),
+    mv( 14, "Swords Dance", NORMAL,   0, 100, 30, RaiseStat),
+    mv( 15, "Cut", NORMAL,  50,  95, 30, NoEffect),
+    mv( 16, "Gust", NORMAL,  40, 100, 35, NoEffect),
+    mv( 17, "Wing Attack", FLYING,  35, 100, 35, NoEffect),
+    mv( 18, "Whirlwind", NORMAL,   0,  85, 20, Other),
+    mv( 19, "Fly", FLYING,  70,  95, 15, TwoTurn),
+    mv( 20, "Bind", NORMAL,  15,  75, 20, Trap),
+    mv( 21, "Slam", NORMAL,  80,  75, 20, NoEffect),
+    mv( 22, "Vine Whip", GRASS,  35, 100, 10, NoEffect),
+    mv( 23, "Stomp", NORMAL,  65, 100, 20, Flinch),
+    mv( 24, "Double Kick", FIGHTING,  30, 100, 30, MultiHit),
+    mv( 25, "Mega Kick", NORMAL, 120,  75,  5, NoEffect),
+    mv( 26, "Jump Kick", FIGHTING,  70,  95, 25, Other),
+    mv( 27, "Rolling Kick", FIGHTING,  60,  85, 15, Flinch),
+    mv( 28, "Sand Attack", NORMAL,   0, 100, 15, LowerStat),
+    mv( 29, "Headbutt", NORMAL,  70, 100, 15, Flinch),
+    mv( 30, "Horn Attack", NORMAL,  65, 100, 25, NoEffect),
+    mv( 31, "Fury Attack", NORMAL,  15,  85, 20, MultiHit),
+    mv( 32, "Horn Drill", NORMAL,   0,  30,  5, OneHitKo),
+    mv( 33, "Tackle", NORMAL,  35,  95, 35, NoEffect),
+    mv( 34, "Body Slam", NORMAL,  85, 100, 15, Paralyze),
+    mv( 35, "Wrap", NORMAL,  15,  85, 20, Trap),
+    mv( 36, "Take Down", NORMAL,  90,  85, 20, Recoil),
+    mv( 37, "Thrash", NORMAL,  90, 100, 20, Rampage),
+    mv( 38, "Double Edge", NORMAL, 100, 100, 15, Recoil),
+    mv( 39, "Tail Whip", NORMAL,   0, 100, 30, LowerStat),
+    mv( 40, "Poison Sting", POISON,  15, 100, 35, Poison),
+    mv( 41, "Twineedle", BUG,  25, 100, 20, MultiHit),
+    mv( 42, "Pin Missile", BUG,  14,  85, 20, MultiHit),
+    mv( 43, "Leer", NORMAL,   0, 100, 30, LowerStat),
+    mv( 44, "Bite", NORMAL,  60, 100, 25, Flinch),
+    mv( 45, "Growl", NORMAL,   0, 100, 40, LowerStat),
+    mv( 46, "Roar", NORMAL,   0, 100, 20, Other),
+    mv( 47, "Sing", NORMAL,   0,  55, 15, Sleep),
+    mv( 48, "Supersonic", NORMAL,   0,  55, 20, Confuse),
+    mv( 49, "Sonic Boom", NORMAL,   0,  90, 20, FixedDamage),
+    mv( 50, "Disable", NORMAL,   0,  55, 20, Other),
+    mv( 51, "Acid", POISON,  40, 100, 30, LowerStat),
+    mv( 52, "Ember", FIRE,  40, 100, 25, Burn),
+    mv( 53, "Flamethrower", FIRE,  95, 100, 15, Burn),
+    mv( 54, "Mist", ICE,   0, 100, 30, Other),
+    mv( 55, "Water Gun", WATER,  40, 100, 25, NoEffect),
+    mv( 56, "Hydro Pump", WATER, 120,  80,  5, NoEffect),
+    mv( 57, "Surf", WATER,  95, 100, 15, NoEffect),
+    mv( 58, "Ice Beam", ICE,  95, 100, 10, Freeze),
+    mv( 59, "Blizzard", ICE, 120,  90,  5, Freeze),
+    mv( 60, "Psybeam", PSYCHIC,  65, 100, 20, Confuse),
+    mv( 61, "Bubble Beam", WATER,  65, 100, 20, LowerStat),
+    mv( 62, "Aurora Beam", ICE,  65, 100, 20, LowerStat),
+    mv( 63, "Hyper Beam", NORMAL, 150,  90,  5, Recharge),
+    mv( 64, "Peck", FLYING,  35, 100, 35, NoEffect),
+    mv( 65, "Drill Peck", FLYING,  80, 100, 20, NoEffect),
+    mv( 66, "Submission", FIGHTING,  80,  80, 25, Recoil),
+    mv( 67, "Low Kick", FIGHTING,  50,  90, 20, Flinch),
+    mv( 68, "Counter", FIGHTING,   0, 100, 20, Other),
+    mv( 69, "Seismic Toss", FIGHTING,   0, 100, 20, FixedDamage),
+    mv( 70, "Strength", NORMAL,  80, 100, 15, NoEffect),
+    mv( 71, "Absorb", GRASS,  20, 100, 20, Drain),
+    mv( 72, "Mega Drain", GRASS,  40, 100, 10, Drain),
+    mv( 73, "Leech Seed", GRASS,   0,  90, 10, Other),
+    mv( 74, "Growth", NORMAL,   0, 100, 40, RaiseStat),
+    mv( 75, "Razor Leaf", GRASS,  55,  95, 25, HighCritical),
+    mv( 76, "Solar Beam", GRASS, 120, 100, 10, TwoTurn),
+    mv( 77, "Poison Powder", POISON,   0,  75, 35, Poison),
+    mv( 78, "Stun Spore", GRASS,   0,  75, 30, Paralyze),
+    mv( 79, "Sleep Powder", GRASS,   0,  75, 15, Sleep),
+    mv( 80, "Petal Dance", GRASS,  70, 100, 20, Rampage),
+    mv( 81, "String Shot", BUG,   0,  95, 40, LowerStat),
+    mv( 82, "Dragon Rage", DRAGON,   0, 100, 10, FixedDamage),
+    mv( 83, "Fire Spin", FIRE,  15,  70, 15, Trap),
+    mv( 84, "Thunder Shock", ELECTRIC,  40, 100, 30, Paralyze),
+    mv( 85, "Thunderbolt", ELECTRIC,  95, 100, 15, Paralyze),
+    mv( 86, "Thunder Wave", ELECTRIC,   0, 100, 20, Paralyze),
+    mv( 87, "Thunder", ELECTRIC, 120,  70, 10, Paralyze),
+    mv( 88, "Rock Throw", ROCK,  50,  65, 15, NoEffect),
+    mv( 89, "Earthquake", GROUND, 100, 100, 10, NoEffect),
+    mv( 90, "Fissure", GROUND,   0,  30,  5, OneHitKo),
+    mv( 91, "Dig", GROUND, 100, 100, 10, TwoTurn),
+    mv( 92, "Toxic", POISON,   0,  85, 10, Poison),
+    mv( 93, "Confusion", PSYCHIC,  50, 100, 25, Confuse),
+    mv( 94, "Psychic", PSYCHIC,  90, 100, 10, LowerStat),
+    mv( 95, "Hypnosis", PSYCHIC,   0,  60, 20, Sleep),
+    mv( 96, "Meditate", PSYCHIC,   0, 100, 40, RaiseStat),
+    mv( 97, "Agility", PSYCHIC,   0, 100, 30, RaiseStat),
+    mv( 98, "Quick Attack", NORMAL,  40, 100, 30, Priority),
+    mv( 99, "Rage", NORMAL,  20, 100, 20, Other),
+    mv(100, "Teleport", PSYCHIC,   0, 100, 20, Other),
+    mv(101, "Night Shade", GHOST,   0, 100, 15, FixedDamage),
+    mv(102, "Mimic", NORMAL,   0, 100, 10, Other),
+    mv(103, "Screech", NORMAL,   0,  85, 40, LowerStat),
+    mv(104, "Double Team", NORMAL,   0, 100, 15, RaiseStat),
+    mv(105, "Recover", NORMAL,   0, 100, 20, Heal),
+    mv(106, "Harden", NORMAL,   0, 100, 30, RaiseStat),
+    mv(107, "Minimize", NORMAL,   0, 100, 20, RaiseStat),
+    mv(108, "Smokescreen", NORMAL,   0, 100, 20, LowerStat),
+    mv(109, "Confuse Ray", GHOST,   0, 100, 10, Confuse),
+    mv(110, "Withdraw", WATER,   0, 100, 40, RaiseStat),
+    mv(111, "Defense Curl", NORMAL,   0, 100, 40, RaiseStat),
+    mv(112, "Barrier", PSYCHIC,   0, 100, 30, RaiseStat),
+    mv(113, "Light Screen", PSYCHIC,   0, 100, 30, Other),
+    mv(114, "Haze", ICE,   0, 100, 30, Other),
+    mv(115, "Reflect", PSYCHIC,   0, 100, 20, Other),
+    mv(116, "Focus Energy", NORMAL,   0, 100, 30, Other),
+    mv(117, "Bide", NORMAL,   0, 100, 10, Other),
+    mv(118, "Metronome", NORMAL,   0, 100, 10, Other),
+    mv(119, "Mirror Move", FLYING,   0, 100, 20, Other),
+    mv(120, "Self-Destruct", NORMAL, 130, 100,  5, Explode),
+    mv(121, "Egg Bomb", NORMAL, 100,  75, 10, NoEffect),
+    mv(122, "Lick", GHOST,  20, 100, 30, Paralyze),
+    mv(123, "Smog", POISON,  20,  70, 20, Poison),
+    mv(124, "Sludge", POISON,  65, 100, 20, Poison),
+    mv(125, "Bone Club", GROUND,  65,  85, 20, Flinch),
+    mv(126, "Fire Blast", FIRE, 120,  85,  5, Burn),
+    mv(127, "Waterfall", WATER,  80, 100, 15, NoEffect),
+    mv(128, "Clamp", WATER,  35,  75, 10, Trap),
+    mv(129, "Swift", NORMAL,  60, 100, 20, Other),
+    mv(130, "Skull Bash", NORMAL, 100, 100, 15, TwoTurn),
+    mv(131, "Spike Cannon", NORMAL,  20, 100, 15, MultiHit),
+    mv(132, "Constrict", NORMAL,  10, 100, 35, LowerStat),
+    mv(133, "Amnesia", PSYCHIC,   0, 100, 20, RaiseStat),
+    mv(134, "Kinesis", PSYCHIC,   0,  80, 15, LowerStat),
+    mv(135, "Soft-Boiled", NORMAL,   0, 100, 10, Heal),
+    mv(136, "High Jump Kick", FIGHTING,  85,  90, 20, Other),
+    mv(137, "Glare", NORMAL,   0,  75, 30, Paralyze),
+    mv(138, "Dream Eater", PSYCHIC, 100, 100, 15, Drain),
+    mv(139, "Poison Gas", POISON,   0,  55, 40, Poison),
+    mv(140, "Barrage", NORMAL,  15,  85, 20, MultiHit),
+    mv(141, "Leech Life", BUG,  20, 100, 15, Drain),
+    mv(142, "Lovely Kiss", NORMAL,   0,  75, 10, Sleep),
+    mv(143, "Sky Attack", FLYING, 140,  90,  5, TwoTurn),
+    mv(144, "Transform", NORMAL,   0, 100, 10, Other),
+    mv(145, "Bubble", WATER,  20, 100, 30, LowerStat),
+    mv(146, "Dizzy Punch", NORMAL,  70, 100, 10, NoEffect),
+    mv(147, "Spore", GRASS,   0, 100, 15, Sleep),
+    mv(148, "Flash", NORMAL,   0,  70, 20, LowerStat),
+    mv(149, "Psywave", PSYCHIC,   0,  80, 15, FixedDamage),
+    mv(150, "Splash", NORMAL,   0, 100, 40, Other),
+    mv(151, "Acid Armor", POISON,   0, 100, 40, RaiseStat),
+    mv(152, "Crabhammer", WATER,  90,  85, 10, HighCritical),
+    mv(153, "Explosion", NORMAL, 170, 100,  5, Explode),
+    mv(154, "Fury Swipes", NORMAL,  18,  80, 15, MultiHit),
+    mv(155, "Bonemerang", GROUND,  50,  90, 10, MultiHit),
+    mv(156, "Rest", PSYCHIC,   0, 100, 10, Heal),
+    mv(157, "Rock Slide", ROCK,  75,  90, 10, NoEffect),
+    mv(158, "Hyper Fang", NORMAL,  80,  90, 15, Flinch),
+    mv(159, "Sharpen", NORMAL,   0, 100, 30, RaiseStat),
+    mv(160, "Conversion", NORMAL,   0, 100, 30, Other),
+    mv(161, "Tri Attack", NORMAL,  80, 100, 10, NoEffect),
+    mv(162, "Super Fang", NORMAL,   0,  90, 10, FixedDamage),
+    mv(163, "Slash", NORMAL,  70, 100, 20, HighCritical),
+    mv(164, "Substitute", NORMAL,   0, 100, 10, Other),
+    mv(165, "Struggle", NORMAL,  50, 100, 10, Recoil),
+];
+
+pub fn all() -> &'static [MoveInfo] {
+    &MOVES
+}
+
+// Looks up a move by id. 0 is an empty move slot, not a move.
+pub fn by_id(id: u8) -> Option<&'static MoveInfo> {
+    MOVES.get((id as usize).checked_sub(1)?)
+}
+
+// Case-insensitive lookup by name, ignoring spaces and punctuation, so
+// "thunderbolt", "Double-Edge" and "SOFTBOILED" all match.
+pub fn by_name(name: &str) -> Option<&'static MoveInfo> {
+    let name = normalize_name(name);
+    MOVES.iter().find(|m| normalize_name(m.name) == name)
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// A real move, stored as its id.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Move(u8);
+
+impl Move {
+
+    pub fn from_id(id: u8) -> Option<Move> {
+        by_id(id).map(|m| Move(m.id))
+    }
+
+    pub fn id(self) -> u8 {
+        self.0
+    }
+
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn info(self) -> &'static MoveInfo {
+        &MOVES[self.0 as usize - 1]
+    }
+}
+
+impl From<Move> for u8 {
+    fn from(m: Move) -> u8 {
+        m.id()
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMoveError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown move \"{}\". Use a name or a move id from 1 to 165", self.input)
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+// Accepts a move name (see by_name()) or a move id such as "85".
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        trimmed.parse::<u8>().ok().map_or_else(|| by_name(trimmed), by_id)
+            .map(|info| Move(info.id))
+            .ok_or_else(|| ParseMoveError { input: s.to_string() })
+    }
+}
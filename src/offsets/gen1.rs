@@ -8,6 +8,7 @@ pub const MONEY_MAX: u32 = 999_999;
 pub const NAME_TERMINATOR: u8 = 0x50;
 
 pub const PLAYER_ID: usize = 0x2605;
+pub const SAVE_FILE_SIZE: usize = 0x8000;
 
 // Item list constants - GEN 1
 pub const BAG_OFFSET: usize = 0x25C9; // Beginning of Bag item list data.
@@ -16,6 +17,7 @@ pub const LIST_ITEM_SIZE: usize = 2;
 
 // This is the offset of the first item in the list relative to the list head
 pub const ITEM_LIST_FIRST_ITEM: usize = 1;
+pub const ITEM_LIST_TERMINATOR: u8 = 0xFF;
 
 // Item box constants
 pub const MAX_BOX_ITEMS: usize = 50;
@@ -26,7 +28,7 @@ pub const PARTY_DATA_OFFSET: usize = 0x2F2C; // Beginning of party data. Party c
 pub const MAX_PARTY_SIZE: usize = 6;
 pub const MAX_POKEMON_BOX_SIZE: usize = 20;
 pub const PARTY_SPECIES_LIST_OFFSET: usize = 1; // Add this to party data offset to get first species in species list.
-pub const _PARTY_LIST_TERMINATOR: u8 = 0xFF;
+pub const PARTY_LIST_TERMINATOR: u8 = 0xFF;
 pub const PARTY_FIRST_OT: usize = 0x303C;
 pub const PARTY_OT_NICK_SIZE: usize = 11;
 pub const PARTY_FIRST_NICK: usize = 0x307E;
@@ -57,6 +59,7 @@ pub const BOX_CURRENT_FIRST_PKMN: usize = BOX_CURRENT_DATA_OFFSET + BOX_START_TO
 // id for the first party pokemon.
 pub const PARTY_SPECIES_ID: usize = 0x00;
 pub const PARTY_CURRENT_HP: usize = 0x01;
+pub const PARTY_BOX_LEVEL: usize = 0x03; // Level as stored in the 33 byte box structure. Only refreshed on deposit, so it can lag behind PARTY_LEVEL.
 pub const PARTY_LEVEL: usize = 0x21;
 pub const PARTY_STATUS: usize = 0x04;
 pub const PARTY_TYPE_1: usize = 0x05;
@@ -75,6 +78,10 @@ pub const PARTY_SPEED_STAT_EXP: usize = 0x17;
 pub const PARTY_SPECIAL_STAT_EXP: usize = 0x19;
 pub const PARTY_IV_1: usize = 0x1B;
 pub const PARTY_IV_2: usize = 0x1C;
+pub const PARTY_PP_1: usize = 0x1D;
+pub const PARTY_PP_2: usize = 0x1E;
+pub const PARTY_PP_3: usize = 0x1F;
+pub const PARTY_PP_4: usize = 0x20;
 pub const PARTY_NEXT_PKMN: usize = 0x2C;
 pub const PARTY_MAX_HP: usize = 0x22;
 pub const PARTY_ATTACK: usize = 0x24;
@@ -86,3 +93,8 @@ pub const BANK2_WHOLE_CHECKSUM: usize = 0x5A4C;
 pub const BANK3_WHOLE_CHECKSUM: usize = 0x7A4C;
 
 
+
+// Per-box checksums follow the whole bank checksum, one byte per box in the bank.
+pub const BANK2_BOX_CHECKSUMS: usize = 0x5A4D;
+pub const BANK3_BOX_CHECKSUMS: usize = 0x7A4D;
+pub const BOXES_PER_BANK: usize = 6;
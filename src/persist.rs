@@ -0,0 +1,122 @@
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::Result;
+
+// Controls how SaveFile::save_with() writes to disk.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SaveOptions {
+    // Number of rotating backups to keep (file.sav.1.bak is the newest). 0 disables backups.
+    pub backups: usize,
+    // Overwrite the file even if something else changed it since it was loaded.
+    pub force: bool,
+}
+
+// How the file on disk compares to a DiskSnapshot.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiskState {
+    Unchanged,
+    Modified,
+    // The file was deleted or moved away since the snapshot was taken.
+    Missing,
+}
+
+// What the file looked like on disk when it was loaded (or last saved), used to notice
+// an emulator writing to it in the meantime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskSnapshot {
+    pub path: PathBuf,
+    pub fingerprint: u64,
+}
+
+impl DiskSnapshot {
+
+    pub fn new(path: &Path, contents: &[u8]) -> Self {
+        DiskSnapshot { path: absolute(path), fingerprint: fingerprint(contents) }
+    }
+
+    pub fn is_for(&self, path: &Path) -> bool {
+        self.path == absolute(path)
+    }
+
+    // Whether the file still has the contents it had when the snapshot was taken.
+    pub fn disk_state(&self) -> Result<DiskState> {
+        match fs::read(&self.path) {
+            Ok(contents) if fingerprint(&contents) == self.fingerprint => Ok(DiskState::Unchanged),
+            Ok(_) => Ok(DiskState::Modified),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DiskState::Missing),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+pub fn fingerprint(contents: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+// Path of the nth backup of `path`, 1 being the newest.
+pub fn backup_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{n}.bak"));
+    PathBuf::from(name)
+}
+
+// Shifts existing backups back by one, dropping the oldest, and copies the current
+// file into the newest slot. Does nothing if the file doesn't exist yet.
+pub fn rotate_backups(path: &Path, count: usize) -> Result<()> {
+    if count == 0 || !path.exists() {
+        return Ok(());
+    }
+
+    let oldest = backup_path(path, count);
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+    for n in (1..count).rev() {
+        let from = backup_path(path, n);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, n + 1))?;
+        }
+    }
+    fs::copy(path, backup_path(path, 1))?;
+    Ok(())
+}
+
+// Temporary file next to `path`, named after it plus the process id and a counter so
+// two writers saving into the same directory never share one.
+fn temp_path(path: &Path) -> PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.{}.tmp", std::process::id(), NEXT.fetch_add(1, Ordering::Relaxed)));
+    path.with_file_name(name)
+}
+
+// Writes to a temporary file next to `path` and renames it into place, so a crash
+// part way through leaves either the old file or the new one, never half of each.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let temp_path = temp_path(path);
+
+    let result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    Ok(result?)
+}
@@ -1,6 +1,15 @@
 use core::fmt;
 
+use bitflags::bitflags;
+
+use crate::error::Result;
+use crate::experience;
+use crate::moves;
 use crate::offsets;
+use crate::save_file::PokemonError;
+use crate::species::{self, GrowthRate, Species};
+use crate::stats::{self, Stats};
+use crate::types::{self, PokemonType};
 
 fn get_high_nibble(b: u8) -> u8 {
     (b >> 4) & 0x0F
@@ -9,10 +18,22 @@ fn get_high_nibble(b: u8) -> u8 {
 fn get_low_nibble(b: u8) -> u8 {
     b & 0x0F
 }
+pub const MAX_DV: u8 = 15;
+const PP_MASK: u8 = 0x3F;
+// Turns left asleep, in the low bits of the status byte. Not a flag, so it stays out of Status.
+const SLEEP_MASK: u8 = 0b0000_0111;
+
+const INVALID_SPECIES_NAME: &str = "Invalid/Unknown Species";
+const INVALID_MOVE_NAME: &str = "Invalid/Unknown";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pokemon {
     pub species_id: u8,
     pub current_hp: u16,
     pub level: u8,
+    // Level byte of the 33 byte box structure. The game only updates it when a pokemon is
+    // deposited, so for a party pokemon it can lag behind `level`.
+    pub box_level: u8,
     pub status: u8,
     pub pkmn_type_1: u8,
     pub pkmn_type_2: u8,
@@ -32,6 +53,8 @@ pub struct Pokemon {
     pub defense_iv: u8,
     pub speed_iv: u8,
     pub special_iv: u8,
+    // Raw PP bytes for each move slot: PP Ups used in the top 2 bits, current PP in the low 6.
+    pub pp: [u8; 4],
     pub max_hp: u16,
     pub attack: u16,
     pub defense: u16,
@@ -50,33 +73,125 @@ impl PokemonRaw {
     }
 
     fn byte(&self, offset: usize) -> u8 {
-        return self.data[offset]
+        self.data[offset]
     }
 
     fn u16_be(&self, offset: usize) -> u16 {
         u16::from_be_bytes([self.data[offset], self.data[offset + 1]])
     }
 
-    fn u16_le(&self, offset: usize) -> u16 {
-        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
-    }
-
     fn u24_be(&self, offset: usize) -> u32 {
         ((self.data[offset] as u32) << 16)
             | ((self.data[offset+1] as u32) << 8)
             | self.data[offset + 2] as u32
     }
 
+    fn set_byte(&mut self, offset: usize, value: u8) {
+        self.data[offset] = value;
+    }
+
+    fn set_u16_be(&mut self, offset: usize, value: u16) {
+        self.data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+    }
+
+    // Experience is 24 bits, anything above that is dropped.
+    fn set_u24_be(&mut self, offset: usize, value: u32) {
+        self.data[offset..offset + 3].copy_from_slice(&value.to_be_bytes()[1..]);
+    }
+
     pub fn get_for_box(&self) -> &[u8] {
-        &self.data[..33]
+        &self.data[..offsets::BOX_NEXT_PKMN]
     }
     pub fn get_for_party(&self) -> &[u8] {
         &self.data
     }
 }
 
+// The four stored DVs, packed in the save as attack/defense in one byte and speed/special in the next.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Dvs {
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DvStat {
+    Attack,
+    Defense,
+    Speed,
+    Special,
+}
+
+// A DV that had to change as a side effect of another edit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DvChange {
+    pub stat: DvStat,
+    pub from: u8,
+    pub to: u8,
+}
+
+impl Dvs {
+
+    pub fn from_bytes(bytes: [u8; 2]) -> Dvs {
+        Dvs {
+            attack: get_high_nibble(bytes[0]),
+            defense: get_low_nibble(bytes[0]),
+            speed: get_high_nibble(bytes[1]),
+            special: get_low_nibble(bytes[1]),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 2] {
+        [(self.attack << 4) | (self.defense & 0x0F), (self.speed << 4) | (self.special & 0x0F)]
+    }
+
+    pub fn is_valid(&self) -> bool {
+        [self.attack, self.defense, self.speed, self.special].iter().all(|dv| *dv <= MAX_DV)
+    }
+
+    // The HP DV, made of the lowest bit of each of the other four.
+    pub fn hp(&self) -> u8 {
+        stats::hp_dv(self.attack, self.defense, self.speed, self.special)
+    }
+
+    // Sets the low bit of each DV so that hp() becomes `hp`, and returns the DVs that changed.
+    // Each changed DV moves by exactly one.
+    pub fn set_hp(&mut self, hp: u8) -> Vec<DvChange> {
+        let mut changes = Vec::new();
+        let dvs = [
+            (DvStat::Attack, &mut self.attack, 3),
+            (DvStat::Defense, &mut self.defense, 2),
+            (DvStat::Speed, &mut self.speed, 1),
+            (DvStat::Special, &mut self.special, 0),
+        ];
+        for (stat, dv, bit) in dvs {
+            let from = *dv;
+            *dv = (from & !1) | ((hp >> bit) & 1);
+            if *dv != from {
+                changes.push(DvChange { stat, from, to: *dv });
+            }
+        }
+        changes
+    }
+}
+
+impl fmt::Display for DvStat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DvStat::Attack => "Attack",
+            DvStat::Defense => "Defense",
+            DvStat::Speed => "Speed",
+            DvStat::Special => "Special",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum StatusCondtion {
+pub enum StatusCondition {
     None,
     Asleep,
     Poisoned,
@@ -85,31 +200,105 @@ pub enum StatusCondtion {
     Paralyzed,
 }
 
-impl fmt::Display for StatusCondtion {
+// Kept for code written against the old spelling.
+pub type StatusCondtion = StatusCondition;
+
+impl fmt::Display for StatusCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
-            StatusCondtion::None => "None",
-            StatusCondtion::Asleep => "Asleep",
-            StatusCondtion::Poisoned => "Poisoned",
-            StatusCondtion::Burned => "Burned",
-            StatusCondtion::Frozen => "Frozen",
-            StatusCondtion::Paralyzed => "Paralyzed",
+            StatusCondition::None => "None",
+            StatusCondition::Asleep => "Asleep",
+            StatusCondition::Poisoned => "Poisoned",
+            StatusCondition::Burned => "Burned",
+            StatusCondition::Frozen => "Frozen",
+            StatusCondition::Paralyzed => "Paralyzed",
         };
 
         write!(f, "{s}")
     }
 }
 
-impl StatusCondtion {
+impl StatusCondition {
+    // The first condition set in a status byte, sleep first. See Status for the full picture.
     pub fn from_byte(value: u8) -> Self {
-        match value {
-            0x04 => StatusCondtion::Asleep,
-            0x08 => StatusCondtion::Poisoned,
-            0x10 => StatusCondtion::Burned,
-            0x20 => StatusCondtion::Frozen,
-            0x40 => StatusCondtion::Paralyzed,
-            _ => StatusCondtion::None,
+        Status::from_bits_retain(value).conditions().first().copied().unwrap_or(StatusCondition::None)
+    }
+}
+
+bitflags! {
+    // The status byte. Sleep isn't a flag: the low three bits count the turns left asleep,
+    // so it is only set and read through asleep(), sleep_turns() and is_asleep().
+    // The game only ever sets one condition, but every bit is kept so the byte round-trips.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Status: u8 {
+        const POISON = 0b0000_1000;
+        const BURN = 0b0001_0000;
+        const FREEZE = 0b0010_0000;
+        const PARALYSIS = 0b0100_0000;
+
+        const _ = !0;
+    }
+}
+
+impl Status {
+
+    pub const MAX_SLEEP_TURNS: u8 = 7;
+
+    // Asleep for `turns` turns (1-7). 0 turns means awake, so it is rejected.
+    pub fn asleep(turns: u8) -> Result<Status> {
+        if !(1..=Status::MAX_SLEEP_TURNS).contains(&turns) {
+            return Err(PokemonError::InvalidStatus.into());
+        }
+        Ok(Status::from_bits_retain(turns))
+    }
+
+    pub fn sleep_turns(&self) -> u8 {
+        self.bits() & SLEEP_MASK
+    }
+
+    pub fn is_asleep(&self) -> bool {
+        self.sleep_turns() > 0
+    }
+
+    // True if no condition is set. Bit 7 isn't used by the game and is ignored.
+    pub fn is_healthy(&self) -> bool {
+        self.conditions().is_empty()
+    }
+
+    pub fn conditions(&self) -> Vec<StatusCondition> {
+        let mut conditions = Vec::new();
+        if self.is_asleep() {
+            conditions.push(StatusCondition::Asleep);
+        }
+        for (flag, condition) in [
+            (Status::POISON, StatusCondition::Poisoned),
+            (Status::BURN, StatusCondition::Burned),
+            (Status::FREEZE, StatusCondition::Frozen),
+            (Status::PARALYSIS, StatusCondition::Paralyzed),
+        ] {
+            if self.contains(flag) {
+                conditions.push(condition);
+            }
+        }
+        conditions
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conditions = self.conditions();
+        if conditions.is_empty() {
+            return write!(f, "{}", StatusCondition::None);
         }
+
+        let names: Vec<String> = conditions.iter()
+            .map(|c| match c {
+                StatusCondition::Asleep if self.sleep_turns() == 1 => format!("{c} (1 turn)"),
+                StatusCondition::Asleep => format!("{c} ({} turns)", self.sleep_turns()),
+                c => c.to_string(),
+            })
+            .collect();
+        write!(f, "{}", names.join(", "))
     }
 }
 
@@ -118,24 +307,7 @@ impl Pokemon {
     
 
     pub fn get_type_name(pkmn_type: u8) -> &'static str {
-        match pkmn_type {
-            0  => "Normal",
-            1  => "Fighting",
-            2  => "Flying",
-            3  => "Poison",
-            4  => "Ground",
-            5  => "Rock",
-            7  => "Bug",
-            8  => "Ghost",
-            20 => "Fire",
-            21 => "Water",
-            22 => "Grass",
-            23 => "Electric",
-            24 => "Psychic",
-            25 => "Ice",
-            26 => "Dragon",
-            _  => "Invalid/Unknown"
-        }
+        PokemonType::from_id(pkmn_type).map_or("Invalid/Unknown", PokemonType::name)
     }
 
 
@@ -144,18 +316,13 @@ impl Pokemon {
 
 
     pub fn from_raw(raw: PokemonRaw) -> Pokemon {
-        let iv1 = raw.byte(offsets::PARTY_IV_1);
-        let iv2 = raw.byte(offsets::PARTY_IV_2);
-
-        let attack_iv = get_high_nibble(iv1);
-        let defense_iv = get_low_nibble(iv1);
-        let speed_iv = get_high_nibble(iv2);
-        let special_iv = get_low_nibble(iv2);
+        let dvs = Dvs::from_bytes([raw.byte(offsets::PARTY_IV_1), raw.byte(offsets::PARTY_IV_2)]);
 
         Pokemon {
             species_id: raw.byte(offsets::PARTY_SPECIES_ID),
             current_hp: raw.u16_be(offsets::PARTY_CURRENT_HP),
             level: raw.byte(offsets::PARTY_LEVEL),
+            box_level: raw.byte(offsets::PARTY_BOX_LEVEL),
             status: raw.byte(offsets::PARTY_STATUS),
             pkmn_type_1: raw.byte(offsets::PARTY_TYPE_1),
             pkmn_type_2: raw.byte(offsets::PARTY_TYPE_2),
@@ -166,12 +333,21 @@ impl Pokemon {
             move_index4: raw.byte(offsets::PARTY_MOVE_INDEX_4),
             ot_id: raw.u16_be(offsets::PARTY_OT_ID),
             experience_pts: raw.u24_be(offsets::PARTY_EXPERIENCE_PTS),
-            hp_stat_exp: raw.u16_le(offsets::PARTY_HP_STAT_EXP),
-            attack_stat_exp: raw.u16_le(offsets::PARTY_ATTACK_STAT_EXP),
-            defense_stat_exp: raw.u16_le(offsets::PARTY_DEFENSE_STAT_EXP),
-            speed_stat_exp: raw.u16_le(offsets::PARTY_SPEED_STAT_EXP),
-            special_stat_exp: raw.u16_le(offsets::PARTY_SPECIAL_STAT_EXP),
-            attack_iv, defense_iv, speed_iv, special_iv,
+            hp_stat_exp: raw.u16_be(offsets::PARTY_HP_STAT_EXP),
+            attack_stat_exp: raw.u16_be(offsets::PARTY_ATTACK_STAT_EXP),
+            defense_stat_exp: raw.u16_be(offsets::PARTY_DEFENSE_STAT_EXP),
+            speed_stat_exp: raw.u16_be(offsets::PARTY_SPEED_STAT_EXP),
+            special_stat_exp: raw.u16_be(offsets::PARTY_SPECIAL_STAT_EXP),
+            attack_iv: dvs.attack,
+            defense_iv: dvs.defense,
+            speed_iv: dvs.speed,
+            special_iv: dvs.special,
+            pp: [
+                raw.byte(offsets::PARTY_PP_1),
+                raw.byte(offsets::PARTY_PP_2),
+                raw.byte(offsets::PARTY_PP_3),
+                raw.byte(offsets::PARTY_PP_4),
+            ],
             max_hp: raw.u16_be(offsets::PARTY_MAX_HP),
             attack: raw.u16_be(offsets::PARTY_ATTACK),
             defense: raw.u16_be(offsets::PARTY_DEFENSE),
@@ -180,377 +356,268 @@ impl Pokemon {
         }
     }
 
+    // Inverse of from_raw(). The result holds the full 44 byte party structure, with get_for_box()
+    // giving its first 33 bytes. Use to_box_raw() for a pokemon going into a box.
+    pub fn to_raw(&self) -> PokemonRaw {
+        let mut raw = PokemonRaw::new([0u8; offsets::PARTY_NEXT_PKMN]);
+
+        raw.set_byte(offsets::PARTY_SPECIES_ID, self.species_id);
+        raw.set_u16_be(offsets::PARTY_CURRENT_HP, self.current_hp);
+        raw.set_byte(offsets::PARTY_BOX_LEVEL, self.box_level);
+        raw.set_byte(offsets::PARTY_STATUS, self.status);
+        raw.set_byte(offsets::PARTY_TYPE_1, self.pkmn_type_1);
+        raw.set_byte(offsets::PARTY_TYPE_2, self.pkmn_type_2);
+        raw.set_byte(offsets::PARTY_CATCH_RATE, self.catch_rate);
+        raw.set_byte(offsets::PARTY_MOVE_INDEX_1, self.move_index1);
+        raw.set_byte(offsets::PARTY_MOVE_INDEX_2, self.move_index2);
+        raw.set_byte(offsets::PARTY_MOVE_INDEX_3, self.move_index3);
+        raw.set_byte(offsets::PARTY_MOVE_INDEX_4, self.move_index4);
+        raw.set_u16_be(offsets::PARTY_OT_ID, self.ot_id);
+        raw.set_u24_be(offsets::PARTY_EXPERIENCE_PTS, self.experience_pts);
+        raw.set_u16_be(offsets::PARTY_HP_STAT_EXP, self.hp_stat_exp);
+        raw.set_u16_be(offsets::PARTY_ATTACK_STAT_EXP, self.attack_stat_exp);
+        raw.set_u16_be(offsets::PARTY_DEFENSE_STAT_EXP, self.defense_stat_exp);
+        raw.set_u16_be(offsets::PARTY_SPEED_STAT_EXP, self.speed_stat_exp);
+        raw.set_u16_be(offsets::PARTY_SPECIAL_STAT_EXP, self.special_stat_exp);
+        let [iv1, iv2] = self.dvs().to_bytes();
+        raw.set_byte(offsets::PARTY_IV_1, iv1);
+        raw.set_byte(offsets::PARTY_IV_2, iv2);
+        raw.set_byte(offsets::PARTY_PP_1, self.pp[0]);
+        raw.set_byte(offsets::PARTY_PP_2, self.pp[1]);
+        raw.set_byte(offsets::PARTY_PP_3, self.pp[2]);
+        raw.set_byte(offsets::PARTY_PP_4, self.pp[3]);
+        raw.set_byte(offsets::PARTY_LEVEL, self.level);
+        raw.set_u16_be(offsets::PARTY_MAX_HP, self.max_hp);
+        raw.set_u16_be(offsets::PARTY_ATTACK, self.attack);
+        raw.set_u16_be(offsets::PARTY_DEFENSE, self.defense);
+        raw.set_u16_be(offsets::PARTY_SPEED, self.speed);
+        raw.set_u16_be(offsets::PARTY_SPECIAL, self.special);
+        raw
+    }
+
+    // Stats this pokemon should have, computed from its species, DVs, stat experience and level.
+    pub fn calculated_stats(&self) -> Result<Stats> {
+        Ok(stats::calc_stats(self).ok_or(PokemonError::UnknownSpecies)?)
+    }
+
+    // Replaces the stored stats with calculated_stats(), which is what the game does when a pokemon
+    // levels up or is withdrawn from the PC. Current HP is capped at the new max HP.
+    pub fn recalculate_stats(&mut self) -> Result<()> {
+        let stats = self.calculated_stats()?;
+        self.max_hp = stats.max_hp;
+        self.attack = stats.attack;
+        self.defense = stats.defense;
+        self.speed = stats.speed;
+        self.special = stats.special;
+        self.current_hp = self.current_hp.min(self.max_hp);
+        Ok(())
+    }
+
+    // True if the stored stats match calculated_stats(). Stats are only recalculated on level up
+    // and withdrawal, so stat experience gained since then also makes this false.
+    pub fn stats_are_consistent(&self) -> bool {
+        self.calculated_stats().is_ok_and(|stats| stats == self.stored_stats())
+    }
+
+    pub fn dvs(&self) -> Dvs {
+        Dvs { attack: self.attack_iv, defense: self.defense_iv, speed: self.speed_iv, special: self.special_iv }
+    }
+
+    // Replaces all four DVs at once. Stats are not touched, call recalculate_stats() afterwards.
+    pub fn set_dvs(&mut self, dvs: Dvs) -> Result<()> {
+        if !dvs.is_valid() {
+            return Err(PokemonError::InvalidDv.into());
+        }
+        self.attack_iv = dvs.attack;
+        self.defense_iv = dvs.defense;
+        self.speed_iv = dvs.speed;
+        self.special_iv = dvs.special;
+        Ok(())
+    }
+
+    pub fn hp_dv(&self) -> u8 {
+        self.dvs().hp()
+    }
+
+    // The HP DV can only be changed through the low bits of the other four DVs.
+    // Returns the DVs that were adjusted to get there.
+    pub fn set_hp_dv(&mut self, hp_dv: u8) -> Result<Vec<DvChange>> {
+        if hp_dv > MAX_DV {
+            return Err(PokemonError::InvalidDv.into());
+        }
+        let mut dvs = self.dvs();
+        let changes = dvs.set_hp(hp_dv);
+        self.set_dvs(dvs)?;
+        Ok(changes)
+    }
+
+    // Both types as stored in the data structure. None if either byte isn't a type.
+    pub fn types(&self) -> Option<(PokemonType, PokemonType)> {
+        Some((PokemonType::from_id(self.pkmn_type_1)?, PokemonType::from_id(self.pkmn_type_2)?))
+    }
+
+    // Attacking types that are super effective against this pokemon, with their multiplier.
+    pub fn weaknesses(&self) -> Result<Vec<(PokemonType, f32)>> {
+        let (type_1, type_2) = self.types().ok_or(PokemonError::InvalidData)?;
+        Ok(types::weaknesses(type_1, type_2))
+    }
+
+    pub fn status(&self) -> Status {
+        Status::from_bits_retain(self.status)
+    }
+
+    pub fn set_status(&mut self, status: Status) {
+        self.status = status.bits();
+    }
+
+    pub fn move_ids(&self) -> [u8; 4] {
+        [self.move_index1, self.move_index2, self.move_index3, self.move_index4]
+    }
+
+    pub fn current_pp(&self, slot: usize) -> Result<u8> {
+        Ok(self.pp[Pokemon::move_slot_index(slot)?] & PP_MASK)
+    }
+
+    pub fn pp_ups(&self, slot: usize) -> Result<u8> {
+        Ok(self.pp[Pokemon::move_slot_index(slot)?] >> 6)
+    }
+
+    // Max PP of the move in `slot` with its PP Ups applied. 0 for an empty slot.
+    pub fn max_pp(&self, slot: usize) -> Result<u8> {
+        let id = self.move_ids()[Pokemon::move_slot_index(slot)?];
+        if id == 0 {
+            return Ok(0);
+        }
+        let info = moves::by_id(id).ok_or(PokemonError::InvalidMove)?;
+        Ok(moves::max_pp(info.pp, self.pp_ups(slot)?))
+    }
+
+    pub fn set_pp(&mut self, slot: usize, pp: u8) -> Result<()> {
+        if pp > self.max_pp(slot)? {
+            return Err(PokemonError::InvalidPp.into());
+        }
+        let index = Pokemon::move_slot_index(slot)?;
+        self.pp[index] = (self.pp[index] & !PP_MASK) | pp;
+        Ok(())
+    }
+
+    // Current PP is kept, unless it is now above the lower max PP.
+    pub fn set_pp_ups(&mut self, slot: usize, pp_ups: u8) -> Result<()> {
+        if pp_ups > moves::MAX_PP_UPS {
+            return Err(PokemonError::InvalidPp.into());
+        }
+        let index = Pokemon::move_slot_index(slot)?;
+        self.pp[index] = (pp_ups << 6) | (self.pp[index] & PP_MASK);
+        let pp = self.current_pp(slot)?.min(self.max_pp(slot)?);
+        self.set_pp(slot, pp)
+    }
+
+    // Puts a move in `slot` with full PP and no PP Ups, like learning it in game.
+    // Move id 0 empties the slot.
+    pub fn set_move(&mut self, slot: usize, move_id: u8) -> Result<()> {
+        let index = Pokemon::move_slot_index(slot)?;
+        let pp = match move_id {
+            0 => 0,
+            id => moves::by_id(id).ok_or(PokemonError::InvalidMove)?.pp,
+        };
+
+        match index {
+            0 => self.move_index1 = move_id,
+            1 => self.move_index2 = move_id,
+            2 => self.move_index3 = move_id,
+            _ => self.move_index4 = move_id,
+        }
+        self.pp[index] = pp;
+        Ok(())
+    }
+
+    // Refills every move to its max PP, keeping PP Ups.
+    pub fn restore_pp(&mut self) -> Result<()> {
+        for slot in 1..=4 {
+            let max_pp = self.max_pp(slot)?;
+            self.set_pp(slot, max_pp)?;
+        }
+        Ok(())
+    }
+
+    fn move_slot_index(slot: usize) -> Result<usize> {
+        if !(1..=4).contains(&slot) {
+            return Err(PokemonError::InvalidMoveSlot.into());
+        }
+        Ok(slot - 1)
+    }
+
+    pub fn growth_rate(&self) -> Result<GrowthRate> {
+        Ok(self.species().ok_or(PokemonError::UnknownSpecies)?.info().growth_rate)
+    }
+
+    pub fn exp_to_next_level(&self) -> Result<u32> {
+        Ok(experience::exp_to_next_level(self.growth_rate()?, self.experience_pts))
+    }
+
+    // Sets the level and the experience to the minimum for that level.
+    // Stats are not touched, call recalculate_stats() to bring them up to date.
+    pub fn set_level(&mut self, level: u8) -> Result<()> {
+        if !(experience::MIN_LEVEL..=experience::MAX_LEVEL).contains(&level) {
+            return Err(PokemonError::InvalidLevel.into());
+        }
+        self.experience_pts = experience::exp_for_level(self.growth_rate()?, level);
+        self.level = level;
+        Ok(())
+    }
+
+    // Sets the experience and the level it corresponds to. Like the game, experience is
+    // capped at what level 100 needs. Stats are not touched.
+    pub fn set_experience(&mut self, exp: u32) -> Result<()> {
+        let growth_rate = self.growth_rate()?;
+        self.experience_pts = exp.min(experience::exp_for_level(growth_rate, experience::MAX_LEVEL));
+        self.level = experience::level_for_exp(growth_rate, self.experience_pts);
+        Ok(())
+    }
+
+    // True if the level is the one the experience points add up to.
+    pub fn level_matches_experience(&self) -> bool {
+        self.growth_rate()
+            .is_ok_and(|rate| experience::level_for_exp(rate, self.experience_pts) == self.level)
+    }
+
+    pub fn stored_stats(&self) -> Stats {
+        Stats { max_hp: self.max_hp, attack: self.attack, defense: self.defense, speed: self.speed, special: self.special }
+    }
+
+    // Same as to_raw() but with the box level byte set to the current level, which is what the
+    // game does when a pokemon is deposited. get_for_box() on the result is the box structure.
+    pub fn to_box_raw(&self) -> PokemonRaw {
+        let mut raw = self.to_raw();
+        raw.set_byte(offsets::PARTY_BOX_LEVEL, self.level);
+        raw
+    }
+
     
+    // The species, or None for MissingNo and other invalid ids.
+    pub fn species(&self) -> Option<Species> {
+        Species::from_index(self.species_id)
+    }
+
     // Function to map pokemon species to ids
     pub fn get_species_name(id: u8) -> &'static str {
+        species::by_index(id).map_or(INVALID_SPECIES_NAME, |s| s.name)
+    }
 
-        const INVALID_SPECIES_NAME: &str = "Invalid/Unknown Species";
-
-        match id {
-            1   => "Rhydon",
-            2   => "Kangaskhan",
-            3   => "Nidoran♂",
-            4   => "Clefairy",
-            5   => "Spearow",
-            6   => "Voltorb",
-            7   => "Nidoking",
-            8   => "Slowbro",
-            9   => "Ivysaur",
-            10  => "Exeggutor",
-            11  => "Lickitung",
-            12  => "Exeggcute",
-            13  => "Grimer",
-            14  => "Gengar",
-            15  => "Nidoran♀",
-            16  => "Nidoqueen",
-            17  => "Cubone",
-            18  => "Rhyhorn",
-            19  => "Lapras",
-            20  => "Arcanine",
-            21  => "Mew",
-            22  => "Gyarados",
-            23  => "Shellder",
-            24  => "Tentacool",
-            25  => "Gastly",
-            26  => "Scyther",
-            27  => "Staryu",
-            28  => "Blastoise",
-            29  => "Pinsir",
-            30  => "Tangela",
-            31  => "MissingNo",
-            32  => "MissingNo",
-            33  => "Growlithe",
-            34  => "Onix",
-            35  => "Fearow",
-            36  => "Pidgey",
-            37  => "Slowpoke",
-            38  => "Kadabra",
-            39  => "Graveler",
-            40  => "Chansey",
-            41  => "Machoke",
-            42  => "Mr. Mime",
-            43  => "Hitmonlee",
-            44  => "Hitmonchan",
-            45  => "Arbok",
-            46  => "Parasect",
-            47  => "Psyduck",
-            48  => "Drowzee",
-            49  => "Golem",
-            50  => "MissingNo",
-            51  => "Magmar",
-            52  => "MissingNo",
-            53  => "Electabuzz",
-            54  => "Magneton",
-            55  => "Koffing",
-            56  => "MissingNo",
-            57  => "Mankey",
-            58  => "Seel",
-            59  => "Diglett",
-            60  => "Tauros",
-            61  => "MissingNo",
-            62  => "MissingNo",
-            63  => "MissingNo",
-            64  => "Farfetch'd",
-            65  => "Venonat",
-            66  => "Dragonite",
-            67  => "MissingNo",
-            68  => "MissingNo",
-            69  => "MissingNo",
-            70  => "Doduo",
-            71  => "Poliwag",
-            72  => "Jynx",
-            73  => "Moltres",
-            74  => "Articuno",
-            75  => "Zapdos",
-            76  => "Ditto",
-            77  => "Meowth",
-            78  => "Krabby",
-            79  => "MissingNo",
-            80  => "MissingNo",
-            81  => "MissingNo",
-            82  => "Vulpix",
-            83  => "Ninetales",
-            84  => "Pikachu",
-            85  => "Raichu",
-            86  => "MissingNo",
-            87  => "MissingNo",
-            88  => "Dratini",
-            89  => "Dragonair",
-            90  => "Kabuto",
-            91  => "Kabutops",
-            92  => "Horsea",
-            93  => "Seadra",
-            94  => "MissingNo",
-            95  => "MissingNo",
-            96  => "Sandshrew",
-            97  => "Sandslash",
-            98  => "Omanyte",
-            99  => "Omastar",
-            100 => "Jigglypuff",
-            101 => "Wigglytuff",
-            102 => "Eevee",
-            103 => "Flareon",
-            104 => "Jolteon",
-            105 => "Vaporeon",
-            106 => "Machop",
-            107 => "Zubat",
-            108 => "Ekans",
-            109 => "Paras",
-            110 => "Poliwhirl",
-            111 => "Poliwrath",
-            112 => "Weedle",
-            113 => "Kakuna",
-            114 => "Beedrill",
-            115 => "MissingNo",
-            116 => "Dodrio",
-            117 => "Primape",
-            118 => "Dugtrio",
-            119 => "Venomoth",
-            120 => "Dewgong",
-            121 => "MissingNo",
-            122 => "MissingNo",
-            123 => "Caterpie",
-            124 => "Metapod",
-            125 => "Butterfree",
-            126 => "Machamp",
-            127 => "MissingNo",
-            128 => "Golduck",
-            129 => "Hypno",
-            130 => "Golbat",
-            131 => "Mewtwo",
-            132 => "Snorlax",
-            133 => "Magikarp",
-            134 => "MissingNo",
-            135 => "MissingNo",
-            136 => "Muk",
-            137 => "MissingNo",
-            138 => "Kingler",
-            139 => "Cloyster",
-            140 => "MissingNo",
-            141 => "Electrode",
-            142 => "Clefable",
-            143 => "Weezing",
-            144 => "Persian",
-            145 => "Marowak",
-            146 => "MissingNo",
-            147 => "Haunter",
-            148 => "Abra",
-            149 => "Alakazam",
-            150 => "Pidgeotto",
-            151 => "Pidgeot",
-            152 => "Starmie",
-            153 => "Bulbasaur",
-            154 => "Venusaur",
-            155 => "Tentacruel",
-            156 => "MissingNo",
-            157 => "Goldeen",
-            158 => "Seaking",
-            159 => "MissingNo",
-            160 => "MissingNo",
-            161 => "MissingNo",
-            162 => "MissingNo",
-            163 => "Ponyta",
-            164 => "Rapidash",
-            165 => "Rattata",
-            166 => "Raticate",
-            167 => "Nidorino",
-            168 => "Nidorina",
-            169 => "Geodude",
-            170 => "Porygon",
-            171 => "Aerodactyl",
-            172 => "MissingNo",
-            173 => "Magnemite",
-            174 => "MissingNo",
-            175 => "MissingNo",
-            176 => "Charmander",
-            177 => "Squirtle",
-            178 => "Charmeleon",
-            179 => "Wartortle",
-            180 => "Charizard",
-            181 => "MissingNo",
-            182 => "MissingNo",
-            183 => "MissingNo",
-            184 => "MissingNo",
-            185 => "Oddish",
-            186 => "Gloom",
-            187 => "Vileplume",
-            188 => "Bellsprout",
-            189 => "Weepinbell",
-            190 => "Victreebel",
-            _ => INVALID_SPECIES_NAME
-        }
+    // True for every real species. MissingNo slots and out of range ids are not valid.
+    pub fn is_valid_species(id: u8) -> bool {
+        species::by_index(id).is_some_and(|s| !s.is_missingno())
     }
 
-    pub fn get_move_name(id: u8) -> &'static str {
-        match id {
-            0   => "<None>",
-            1   => "Pound",
-            2   => "Karate Chop",
-            3   => "Double Slap",
-            4   => "Comet Punch",
-            6   => "Mega Punch",
-            7   => "Fire Punch",
-            8   => "Ice Punch",
-            9   => "Thunder Punch",
-            10  => "Scratch",
-            11  => "Vise Grip",
-            12  => "Guillotine",
-            13  => "Razor Wind",
-            14  => "Swords Dance",
-            15  => "Cut",
-            16  => "Gust",
-            17  => "Wing Attack",
-            18  => "Whirlwind",
-            19  => "Fly",
-            20  => "Bind",
-            21  => "Slam",
-            22  => "Vine Whip",
-            23  => "Stomp",
-            24  => "Double Kick",
-            25  => "Mega Kick",
-            26  => "Jump Kick",
-            27  => "Rolling Kick",
-            28  => "Sand Attack",
-            29  => "Headbutt",
-            30  => "Horn Attack",
-            31  => "Fury Attack",
-            32  => "Horn Drill",
-            33  => "Tackle",
-            34  => "Body Slam",
-            35  => "Wrap",
-            36  => "Take Down",
-            37  => "Thrash",
-            38  => "Double Edge",
-            39  => "Tail Whip",
-            40  => "Poison Sting",
-            41  => "Twineedle",
-            42  => "Pin Missile",
-            43  => "Leer",
-            44  => "Bite",
-            45  => "Growl",
-            46  => "Roar",
-            47  => "Sing",
-            48  => "Supersonic",
-            49  => "Sonic Boom",
-            50  => "Disable",
-            51  => "Acid",
-            52  => "Ember",
-            53  => "Flamethrower",
-            54  => "Mist",
-            55  => "Water Gun",
-            56  => "Hydro Pump",
-            57  => "Surf",
-            58  => "Ice Beam",
-            59  => "Blizzard",
-            60  => "Psybeam",
-            61  => "Bubble Beam",
-            62  => "Aurora Beam",
-            63  => "Hyper Beam",
-            64  => "Peck",
-            65  => "Drill Peck",
-            66  => "Submission",
-            67  => "Low Kick",
-            68  => "Counter",
-            69  => "Seismic Toss",
-            70  => "Strength",
-            71  => "Absorb",
-            72  => "Mega Drain",
-            73  => "Leech Seed",
-            74  => "Growth",
-            75  => "Razor Leaf",
-            76  => "Solar Beam",
-            77  => "Poison Powder",
-            78  => "Stun Spore",
-            79  => "Sleep Powder",
-            80  => "Petal Dance",
-            81  => "String Shot",
-            82  => "Dragon Rage",
-            83  => "Fire Spin",
-            84  => "Thunder Shock",
-            85  => "Thunderbolt",
-            86  => "Thunder Wave",
-            87  => "Thunder",
-            88  => "Rock Throw",
-            89  => "Earthquake",
-            90  => "Fissure",
-            91  => "Dig",
-            92  => "Toxic",
-            93  => "Confusion",
-            94  => "Psychic",
-            95  => "Hypnosis",
-            96  => "Meditate",
-            97  => "Agility",
-            98  => "Quick Attack",
-            99  => "Rage",
-            100 => "Teleport",
-            101 => "Night Shade",
-            102 => "Mimic",
-            103 => "Screech",
-            104 => "Double Team",
-            105 => "Recover",
-            106 => "Harden",
-            107 => "Minimize",
-            108 => "Smokescreen",
-            109 => "Confuse Ray",
-            110 => "Withdraw",
-            111 => "Defense Curl",
-            112 => "Barrier",
-            113 => "Light Screen",
-            114 => "Haze",
-            115 => "Reflect",
-            116 => "Focus Energy",
-            117 => "Bide",
-            118 => "Metronome",
-            119 => "Mirror Move",
-            120 => "Self-Destruct",
-            121 => "Egg Bomb",
-            122 => "Lick",
-            123 => "Smog",
-            124 => "Sludge",
-            125 => "Bone Club",
-            126 => "Fire Blast",
-            127 => "Waterfall",
-            128 => "Clamp",
-            129 => "Swift",
-            130 => "Skull Bash",
-            131 => "Spike Cannon",
-            132 => "Constrict",
-            133 => "Amnesia",
-            134 => "Kinesis",
-            135 => "Soft-Boiled",
-            136 => "High Jump Kick",
-            137 => "Glare",
-            138 => "Dream Eater",
-            139 => "Poison Gas",
-            140 => "Barrage",
-            141 => "Leech Life",
-            142 => "Lovely Kiss",
-            143 => "Sky Attack",
-            144 => "Transform",
-            145 => "Bubble",
-            146 => "Dizzy Punch",
-            147 => "Spore",
-            148 => "Flash",
-            149 => "Psywave",
-            150 => "Splash",
-            151 => "Acid Armor",
-            152 => "Crabhammer",
-            153 => "Explosion",
-            154 => "Fury Swipes",
-            155 => "Bonemerang",
-            156 => "Rest",
-            157 => "Rock Slide",
-            158 => "Hyper Fang",
-            159 => "Sharpen",
-            160 => "Conversion",
-            161 => "Tri Attack",
-            162 => "Super Fang",
-            163 => "Slash",
-            164 => "Substitute",
-            165 => "Struggle",
-            _ => "Invalid/Unknown"
+    pub fn is_valid_move(id: u8) -> bool {
+        Pokemon::get_move_name(id) != INVALID_MOVE_NAME
+    }
 
+    pub fn get_move_name(id: u8) -> &'static str {
+        if id == 0 {
+            return "<None>";
         }
+        moves::by_id(id).map_or(INVALID_MOVE_NAME, |m| m.name)
     }
 }
 
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::checksum::ChecksumRegion;
+use crate::error::Result;
+use crate::offsets;
+use crate::save_file::{ItemStorage, PokemonLocation, PokemonStorage, SaveFile};
+use crate::validator::NameField;
+
+// A single change made by SaveRepairer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepairAction {
+    ClampedPokemonCount { storage: PokemonStorage, from: u8, to: u8 },
+    RebuiltSpeciesEntry { location: PokemonLocation, from: u8, to: u8 },
+    RestoredSpeciesListTerminator { storage: PokemonStorage, offset: usize },
+    ClampedItemCount { storage: ItemStorage, from: u8, to: u8 },
+    RestoredItemListTerminator { storage: ItemStorage, offset: usize },
+    TerminatedName { field: NameField, offset: usize },
+    UpdatedChecksum { region: ChecksumRegion, from: u8, to: u8 },
+}
+
+// Fixes the structural problems that can be fixed without guessing at lost data. Anything
+// else (unknown species, moves or items, bad money digits) is left for the caller to handle.
+pub struct SaveRepairer<'a> {
+    save: &'a mut SaveFile,
+    actions: Vec<RepairAction>,
+}
+
+impl<'a> SaveRepairer<'a> {
+
+    pub fn new(save: &'a mut SaveFile) -> Self {
+        SaveRepairer { save, actions: Vec::new() }
+    }
+
+    // Runs every repair and returns the list of changes made.
+    pub fn repair(mut self) -> Result<Vec<RepairAction>> {
+        self.terminate_name(NameField::Player, offsets::PLAYER_NAME_OFFSET)?;
+        self.terminate_name(NameField::Rival, offsets::RIVAL_NAME_OFFSET)?;
+        self.repair_items(ItemStorage::Bag)?;
+        self.repair_items(ItemStorage::PcBox)?;
+
+        self.repair_pokemon_list(PokemonStorage::Party)?;
+        for box_number in 1..=offsets::NUM_POKEMON_BOXES {
+            self.repair_pokemon_list(PokemonStorage::PcBox(box_number))?;
+        }
+
+        // Checksums go last so they cover every fix above.
+        self.repair_checksums()?;
+
+        Ok(self.actions)
+    }
+
+    fn terminate_name(&mut self, field: NameField, offset: usize) -> Result<()> {
+        let name = self.save.read_bytes(offset, offsets::PARTY_OT_NICK_SIZE)?;
+
+        if !name.contains(&offsets::NAME_TERMINATOR) {
+            self.save.write_byte(offset + offsets::PARTY_OT_NICK_SIZE - 1, offsets::NAME_TERMINATOR)?;
+            self.actions.push(RepairAction::TerminatedName { field, offset });
+        }
+        Ok(())
+    }
+
+    fn repair_items(&mut self, storage: ItemStorage) -> Result<()> {
+        let (list_offset, max) = match storage {
+            ItemStorage::Bag => (offsets::BAG_OFFSET, offsets::MAX_BAG_ITEMS),
+            ItemStorage::PcBox => (offsets::BOX_ITEMS_OFFSET, offsets::MAX_BOX_ITEMS),
+        };
+
+        let count = self.save.read_byte(list_offset)?;
+        if count as usize > max {
+            self.save.write_byte(list_offset, max as u8)?;
+            self.actions.push(RepairAction::ClampedItemCount { storage, from: count, to: max as u8 });
+        }
+
+        let count = self.save.read_byte(list_offset)? as usize;
+        let terminator_offset = list_offset + offsets::ITEM_LIST_FIRST_ITEM + offsets::LIST_ITEM_SIZE * count;
+        if self.save.read_byte(terminator_offset)? != offsets::ITEM_LIST_TERMINATOR {
+            self.save.write_byte(terminator_offset, offsets::ITEM_LIST_TERMINATOR)?;
+            self.actions.push(RepairAction::RestoredItemListTerminator { storage, offset: terminator_offset });
+        }
+        Ok(())
+    }
+
+    fn repair_pokemon_list(&mut self, storage: PokemonStorage) -> Result<()> {
+        let layout = self.save.pokemon_list_layout(storage)?;
+
+        let count = self.save.read_byte(layout.count_offset)?;
+        if count as usize > layout.capacity {
+            self.save.write_byte(layout.count_offset, layout.capacity as u8)?;
+            self.actions.push(RepairAction::ClampedPokemonCount { storage, from: count, to: layout.capacity as u8 });
+        }
+        let count = self.save.read_byte(layout.count_offset)? as usize;
+
+        // The count byte is trusted. Each species list entry is rebuilt from the species byte of the
+        // pokemon data it belongs to, since that is what the game uses once the pokemon is loaded.
+        let species_list = layout.count_offset + 1;
+        for index in 0..count {
+            let struct_offset = layout.first_pokemon + layout.pokemon_size * index;
+            let struct_species = self.save.read_byte(struct_offset + offsets::PARTY_SPECIES_ID)?;
+            let list_species = self.save.read_byte(species_list + index)?;
+
+            if list_species != struct_species {
+                self.save.write_byte(species_list + index, struct_species)?;
+                self.actions.push(RepairAction::RebuiltSpeciesEntry {
+                    location: PokemonLocation { storage, slot: index + 1 },
+                    from: list_species,
+                    to: struct_species,
+                });
+            }
+        }
+
+        let terminator_offset = species_list + count;
+        if self.save.read_byte(terminator_offset)? != offsets::PARTY_LIST_TERMINATOR {
+            self.save.write_byte(terminator_offset, offsets::PARTY_LIST_TERMINATOR)?;
+            self.actions.push(RepairAction::RestoredSpeciesListTerminator { storage, offset: terminator_offset });
+        }
+
+        for index in 0..count {
+            let location = PokemonLocation { storage, slot: index + 1 };
+            self.terminate_name(NameField::OtName(location), layout.first_ot + offsets::PARTY_OT_NICK_SIZE * index)?;
+            self.terminate_name(NameField::Nickname(location), layout.first_nick + offsets::PARTY_OT_NICK_SIZE * index)?;
+        }
+        Ok(())
+    }
+
+    fn repair_checksums(&mut self) -> Result<()> {
+        let report = self.save.verify_checksums()?;
+
+        for region in report.mismatches() {
+            self.save.write_checksum(region.region, region.computed)?;
+            self.actions.push(RepairAction::UpdatedChecksum {
+                region: region.region,
+                from: region.stored,
+                to: region.computed,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RepairAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairAction::ClampedPokemonCount { storage, from, to } =>
+                write!(f, "{storage} count clamped from {from} to {to}"),
+            RepairAction::RebuiltSpeciesEntry { location, from, to } =>
+                write!(f, "{location} species list entry changed from {from} to {to}"),
+            RepairAction::RestoredSpeciesListTerminator { storage, offset } =>
+                write!(f, "{storage} species list terminator restored at 0x{offset:04X}"),
+            RepairAction::ClampedItemCount { storage, from, to } =>
+                write!(f, "{storage} item count clamped from {from} to {to}"),
+            RepairAction::RestoredItemListTerminator { storage, offset } =>
+                write!(f, "{storage} item list terminator restored at 0x{offset:04X}"),
+            RepairAction::TerminatedName { field, offset } =>
+                write!(f, "{field} at 0x{offset:04X} terminated"),
+            RepairAction::UpdatedChecksum { region, from, to } =>
+                write!(f, "{region} checksum updated from 0x{from:02X} to 0x{to:02X}"),
+        }
+    }
+}
@@ -1,17 +1,29 @@
 use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
 
 use bitflags::bitflags;
 
+use crate::error::{ChucksumError, Result};
+use crate::checksum::{ChecksumRegion, ChecksumReport, RegionChecksum};
+use crate::format::SaveContainer;
 use crate::items;
-use crate::pokemon::Pokemon;
+use crate::journal::{Checkpoint, Journal, JournalEntry, SaveField};
+use crate::persist::{self, DiskSnapshot, DiskState, SaveOptions};
+use crate::pokemon::{Dvs, Pokemon, Status};
 use crate::pokemon::PokemonRaw;
+use crate::repair::{RepairAction, SaveRepairer};
 use crate::textencoding;
+use crate::validator::{Diagnostic, SaveValidator};
 use crate::offsets;
 
 
 
 pub struct SaveFile {
-    data: Vec<u8>
+    data: Vec<u8>,
+    container: SaveContainer,
+    disk_snapshot: Option<DiskSnapshot>,
+    journal: Journal,
 }
 
 
@@ -44,15 +56,66 @@ pub enum PokemonError {
     InvalidData,
     InvalidPartySlot,
     PokemonBoxFull,
-    BoxInUse,
+    InvalidBoxSlot,
+    UnknownSpecies,
+    InvalidLevel,
+    InvalidDv,
+    InvalidMoveSlot,
+    InvalidMove,
+    InvalidPp,
+    InvalidStatus,
+    PartyFull,
+    LastPartyPokemon,
 }
 
-
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ItemStorage {
     PcBox,
     Bag,
 }
 
+// A list of pokemon in the save: the party or one of the 12 PC boxes (numbered 1-12).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PokemonStorage {
+    Party,
+    PcBox(usize),
+}
+
+// A single pokemon slot. Slots are numbered from 1 like party slots in the game.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PokemonLocation {
+    pub storage: PokemonStorage,
+    pub slot: usize,
+}
+
+// A pokemon read from the save together with the names kept outside its data structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredPokemon {
+    pub location: PokemonLocation,
+    pub pokemon: Pokemon,
+    pub nickname: String,
+    pub ot_name: String,
+}
+
+// Offsets of the parts of a pokemon list. The count byte is followed by the species list,
+// then the pokemon data structures, then the OT names and finally the nicknames.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PokemonListLayout {
+    pub count_offset: usize,
+    pub capacity: usize,
+    pub first_pokemon: usize,
+    pub pokemon_size: usize,
+    pub first_ot: usize,
+    pub first_nick: usize,
+}
+
+// A pokemon's data structure and names exactly as stored in a list, for moving it to another slot or list.
+struct ListEntry {
+    data: Vec<u8>,
+    ot_name: Vec<u8>,
+    nickname: Vec<u8>,
+}
+
 pub struct ItemStorageOffsets {
     offset: usize,
     max_items: usize,
@@ -60,6 +123,34 @@ pub struct ItemStorageOffsets {
     
 }
 
+impl std::fmt::Display for ItemStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemStorage::Bag => write!(f, "Bag"),
+            ItemStorage::PcBox => write!(f, "PC item box"),
+        }
+    }
+}
+
+impl std::fmt::Display for PokemonStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PokemonStorage::Party => write!(f, "Party"),
+            PokemonStorage::PcBox(box_number) => write!(f, "Box {box_number}"),
+        }
+    }
+}
+
+impl std::fmt::Display for PokemonLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} slot {}", self.storage, self.slot)
+    }
+}
+
+impl std::error::Error for BagError {}
+
+impl std::error::Error for PokemonError {}
+
 impl std::fmt::Display for BagError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -78,152 +169,363 @@ impl std::fmt::Display for PokemonError {
             PokemonError::InvalidData => write!(f, "Invalid or corrupted data"),
             PokemonError::InvalidPartySlot => write!(f, "Invalid party slot! Should be an integer between 1 and 6"),
             PokemonError::PokemonBoxFull => write!(f, "Pokemon box is full! Aborting."),
-            PokemonError::BoxInUse => write!(f, "Cannot copy pokemon to the current box. Select another box with a free slot and try again."),
+            PokemonError::PartyFull => write!(f, "Party is full! Aborting."),
+            PokemonError::LastPartyPokemon => write!(f, "Can't remove the last pokemon in the party! Aborting."),
+            PokemonError::InvalidBoxSlot => write!(f, "Invalid box slot! There is no pokemon in that slot"),
+            PokemonError::UnknownSpecies => write!(f, "Unknown species, no base stats available"),
+            PokemonError::InvalidLevel => write!(f, "Invalid level! Should be an integer between 1 and 100"),
+            PokemonError::InvalidDv => write!(f, "Invalid DV! Should be an integer between 0 and 15"),
+            PokemonError::InvalidMoveSlot => write!(f, "Invalid move slot! Should be an integer between 1 and 4"),
+            PokemonError::InvalidMove => write!(f, "Invalid move! Should be a move id between 1 and 165"),
+            PokemonError::InvalidPp => write!(f, "Invalid PP! Can't be more than the move's max PP or 3 PP Ups"),
+            PokemonError::InvalidStatus => write!(f, "Invalid status! Sleep lasts between 1 and 7 turns"),
         }
     }
 }
 
 impl SaveFile {
     
-    pub fn new(filename: &str) -> std::io::Result<Self> {
+    pub fn new(filename: &str) -> Result<Self> {
         
         let data = fs::read(filename)?;
-        Ok(Self { data })
+        let disk_snapshot = Some(DiskSnapshot::new(Path::new(filename), &data));
+
+        let mut save_file = Self::from_bytes(data)?;
+        save_file.disk_snapshot = disk_snapshot;
+        Ok(save_file)
+    }
+
+    // Builds a save from the contents of a save file already in memory.
+    pub fn from_bytes(mut data: Vec<u8>) -> Result<Self> {
+
+        // Every offset in the crate assumes a bare 32 KiB SRAM image. Padding and footers
+        // are stripped here and put back by to_bytes().
+        let container = SaveContainer::detect(&data)?;
+        data.truncate(offsets::SAVE_FILE_SIZE);
+
+        Ok(Self { data, container, disk_snapshot: None, journal: Journal::new() })
+    }
+
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        Self::from_bytes(data.to_vec())
+    }
+
+    pub fn from_reader(mut reader: impl Read) -> Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::from_bytes(data)
+    }
+
+    // Updates all checksums and returns the file contents in the shape the save was loaded in.
+    pub fn to_bytes(&mut self) -> Result<Vec<u8>> {
+
+        // Calculate and update checksums. Important, do not skip or file will not be recognized as corrupted by the game
+        self.update_checksums()?;
+
+        Ok(self.container.wrap(&self.data))
+    }
+
+    pub fn write_to(&mut self, mut writer: impl Write) -> Result<()> {
+        writer.write_all(&self.to_bytes()?)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    // The kind of file this save was loaded from.
+    pub fn container(&self) -> &SaveContainer {
+        &self.container
     }
     
     pub fn len(&self) -> usize { self.data.len() }
+
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    // Returns `len` bytes starting at `offset`, or an error if any of them are past the end of the data.
+    pub fn read_bytes(&self, offset: usize, len: usize) -> Result<&[u8]> {
+        offset.checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or(ChucksumError::OutOfBounds { offset, len, file_len: self.len() })
+    }
     
-    pub fn read_byte(&self, offset: usize) -> u8 {
-        self.data[offset]
+    pub fn read_byte(&self, offset: usize) -> Result<u8> {
+        Ok(self.read_bytes(offset, 1)?[0])
     }
     
-    pub fn read_u16_be(&self, offset: usize) -> u16 {
-        u16::from_be_bytes([self.data[offset], self.data[offset + 1]])
+    pub fn read_u16_be(&self, offset: usize) -> Result<u16> {
+        let bytes = self.read_bytes(offset, 2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
     }
     
-    pub fn _read_u16_le(&self, offset: usize) -> u16 {
-        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    pub fn _read_u16_le(&self, offset: usize) -> Result<u16> {
+        let bytes = self.read_bytes(offset, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
     }
     
-    pub fn write_byte(&mut self, offset: usize, value: u8) {
-        self.data[offset] = value;
+    pub fn write_byte(&mut self, offset: usize, value: u8) -> Result<()> {
+        self.write_bytes(offset, &[value])
     }
     
-    pub fn write_bytes(&mut self, offset: usize, data: &[u8] ) {
-        let end = offset + data.len();
-        
-        if end > self.len() {
-            return;
+    // Every write is recorded in the journal so it can be undone or rolled back. Writes that
+    // don't change anything are not recorded.
+    pub fn write_bytes(&mut self, offset: usize, data: &[u8] ) -> Result<()> {
+        let before = self.read_bytes(offset, data.len())?.to_vec();
+        if before == data {
+            return Ok(());
         }
+
+        self.write_unjournaled(offset, data)?;
+        self.journal.record(JournalEntry { offset, before, after: data.to_vec() });
+        Ok(())
+    }
+
+    fn write_unjournaled(&mut self, offset: usize, data: &[u8]) -> Result<()> {
+        let file_len = self.len();
+        let destination = offset.checked_add(data.len())
+            .and_then(|end| self.data.get_mut(offset..end))
+            .ok_or(ChucksumError::OutOfBounds { offset, len: data.len(), file_len })?;
         
-        self.data[offset..end].copy_from_slice(data);
+        destination.copy_from_slice(data);
+        Ok(())
+    }
+
+    // Every write made since the save was loaded.
+    pub fn journal(&self) -> &Journal {
+        &self.journal
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.journal.checkpoint()
+    }
+
+    // Reverts every write made after `checkpoint`. Rolled back writes can't be redone.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> Result<()> {
+        for entry in self.journal.rollback(checkpoint) {
+            self.write_unjournaled(entry.offset, &entry.before)?;
+        }
+        Ok(())
+    }
+
+    // Runs `edit` and rolls back everything it wrote if it returns an error,
+    // so a failed multi-step edit never leaves the save half modified.
+    // The writes of the outermost transaction are undone and redone as one edit.
+    pub fn transaction<T>(&mut self, edit: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let checkpoint = self.checkpoint();
+        self.journal.begin_group();
+        let result = edit(self);
+        self.journal.end_group();
+        if result.is_err() {
+            self.rollback_to(checkpoint)?;
+        }
+        result
+    }
+
+    // Reverts the most recent edit. Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> Result<bool> {
+        let Some(edit) = self.journal.undo().map(<[JournalEntry]>::to_vec) else { return Ok(false) };
+        for entry in edit.iter().rev() {
+            self.write_unjournaled(entry.offset, &entry.before)?;
+        }
+        Ok(true)
+    }
+
+    // Reapplies the most recently undone edit. Returns false if there was nothing to redo.
+    pub fn redo(&mut self) -> Result<bool> {
+        let Some(edit) = self.journal.redo().map(<[JournalEntry]>::to_vec) else { return Ok(false) };
+        for entry in &edit {
+            self.write_unjournaled(entry.offset, &entry.after)?;
+        }
+        Ok(true)
+    }
+
+    // Offsets whose value differs from what was loaded. A byte written and then
+    // set back to its original value is not dirty.
+    pub fn dirty_offsets(&self) -> Vec<usize> {
+        self.journal.original_bytes().into_iter()
+            .filter(|(offset, original)| self.data[*offset] != *original)
+            .map(|(offset, _)| offset)
+            .collect()
+    }
+
+    // The fields touched by dirty_offsets(), each listed once in offset order.
+    pub fn dirty_fields(&self) -> Result<Vec<SaveField>> {
+        let mut fields = Vec::new();
+        for offset in self.dirty_offsets() {
+            let field = self.field_at(offset)?;
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        Ok(fields)
+    }
+
+    // Works out which part of the save `offset` belongs to.
+    fn field_at(&self, offset: usize) -> Result<SaveField> {
+        let within = |start: usize, len: usize| (start..start + len).contains(&offset);
+        let item_list_len = |max: usize| offsets::ITEM_LIST_FIRST_ITEM + offsets::LIST_ITEM_SIZE * max + 1;
+
+        if within(offsets::PLAYER_NAME_OFFSET, offsets::PARTY_OT_NICK_SIZE) {
+            return Ok(SaveField::PlayerName);
+        }
+        if within(offsets::RIVAL_NAME_OFFSET, offsets::PARTY_OT_NICK_SIZE) {
+            return Ok(SaveField::RivalName);
+        }
+        if within(offsets::MONEY_OFFSET, 3) {
+            return Ok(SaveField::Money);
+        }
+        if offset == offsets::BADGES {
+            return Ok(SaveField::Badges);
+        }
+        if within(offsets::PLAYER_ID, 2) {
+            return Ok(SaveField::PlayerId);
+        }
+        if within(offsets::BAG_OFFSET, item_list_len(offsets::MAX_BAG_ITEMS)) {
+            return Ok(SaveField::Items(ItemStorage::Bag));
+        }
+        if within(offsets::BOX_ITEMS_OFFSET, item_list_len(offsets::MAX_BOX_ITEMS)) {
+            return Ok(SaveField::Items(ItemStorage::PcBox));
+        }
+        if offset == offsets::CURRENT_BOX {
+            return Ok(SaveField::CurrentBox);
+        }
+        if let Some(region) = ChecksumRegion::all().into_iter().find(|r| r.checksum_offset() == offset) {
+            return Ok(SaveField::Checksum(region));
+        }
+
+        let storages = std::iter::once(PokemonStorage::Party)
+            .chain((1..=offsets::NUM_POKEMON_BOXES).map(PokemonStorage::PcBox));
+        for storage in storages {
+            let layout = self.pokemon_list_layout(storage)?;
+            if within(layout.count_offset, layout.capacity + 2) {
+                return Ok(SaveField::PokemonList(storage));
+            }
+
+            let slot = (1..=layout.capacity).find(|slot| {
+                within(layout.first_pokemon + layout.pokemon_size * (slot - 1), layout.pokemon_size)
+                    || within(layout.first_ot + offsets::PARTY_OT_NICK_SIZE * (slot - 1), offsets::PARTY_OT_NICK_SIZE)
+                    || within(layout.first_nick + offsets::PARTY_OT_NICK_SIZE * (slot - 1), offsets::PARTY_OT_NICK_SIZE)
+            });
+            if let Some(slot) = slot {
+                return Ok(SaveField::Pokemon(PokemonLocation { storage, slot }));
+            }
+        }
+
+        Ok(SaveField::Other(offset))
     }
     
     pub fn _as_slice(&self) -> &[u8] {
         &self.data
     }
     
-    pub fn save(&mut self, filename: &str) -> std::io::Result<()> {
-        
-        // Calculate and update checksum. Important, do not skip or file will not be recognized as corrupted by the game
-        self.write_byte(offsets::CHECKSUM_OFFSET, self.calculate_checksum(offsets::CHECKSUM_START, offsets::CHECKSUM_END));
-
-        // println!("All boxes calculated: 0x{:02X}",self.calculate_checksum(0x4000, 0x5A4B));
-        // println!("Should be: 0x{:02X}", self.read_byte(0x5A4C));
-
-        // println!("Box 1 calculated: 0x{:02X}",self.calculate_checksum(0x4000, 0x4461));
-        // println!("Should be: 0x{:02X}", self.read_byte(0x5A4D));
-
-        self.update_box_checksums();
+    pub fn save(&mut self, filename: &str) -> Result<()> {
+        self.save_with(filename, &SaveOptions::default())
+    }
 
+    // Saves atomically, optionally keeping rotating backups. Refuses to overwrite the file this
+    // save was loaded from if it changed or disappeared on disk since then, unless options.force is set.
+    pub fn save_with(&mut self, filename: &str, options: &SaveOptions) -> Result<()> {
+        let path = Path::new(filename);
 
+        if let Some(snapshot) = &self.disk_snapshot
+            && !options.force
+            && snapshot.is_for(path)
+        {
+            match snapshot.disk_state()? {
+                DiskState::Unchanged => {}
+                DiskState::Modified => return Err(ChucksumError::ModifiedOnDisk { path: path.to_path_buf() }),
+                DiskState::Missing => return Err(ChucksumError::MissingOnDisk { path: path.to_path_buf() }),
+            }
+        }
         
-        // Write save data to file
-        fs::write(filename, &self.data)
+        let contents = self.to_bytes()?;
+        persist::rotate_backups(path, options.backups)?;
+        persist::write_atomic(path, &contents)?;
+
+        // Later saves to this file are checked against what we just wrote. Saving a copy somewhere
+        // else leaves the snapshot of the original alone, so it is still checked.
+        if self.disk_snapshot.as_ref().is_none_or(|snapshot| snapshot.is_for(path)) {
+            self.disk_snapshot = Some(DiskSnapshot::new(path, &contents));
+        }
+        Ok(())
     }
     
-    fn calculate_checksum(&self, start: usize, end: usize) -> u8 {
+    fn calculate_checksum(&self, start: usize, end: usize) -> Result<u8> {
         let mut checksum: u8 = 0;
         
-        for byte in &self.data[start..=end] {
+        for byte in self.read_bytes(start, end - start + 1)? {
             checksum = checksum.wrapping_add(*byte);
         }
         // println!("checksum: 0x{:04X}: 0x{:02X}", offsets::CHECKSUM_OFFSET, !checksum);
-        !checksum
+        Ok(!checksum)
     }
 
-    fn update_box_checksums(&mut self) {
-        // Calculate and update whole bank checksums for bank 2
-        let mut checksum = self.calculate_checksum(0x4000, 0x5A4B);
-        self.write_byte(offsets::BANK2_WHOLE_CHECKSUM, checksum);
-
-        checksum = self.calculate_checksum(0x6000, 0x7A4B);
-        self.write_byte(offsets::BANK3_WHOLE_CHECKSUM, checksum);
-        
-        let mut current_offset = 0x4000;
-        let current_to_end = 0x461;
-        let mut current_write_offset = offsets::BANK2_WHOLE_CHECKSUM + 1;
-
-        for _ in 0..6 {
-            checksum = self.calculate_checksum(current_offset, current_offset + current_to_end);
-            self.write_byte(current_write_offset, checksum);
-            current_offset += offsets::BOX_NEXT_BOX;
-            current_write_offset += 1;
+    // Rewrites the main, whole bank and per-box checksums so they match the current data.
+    fn update_checksums(&mut self) -> Result<()> {
+        for region in ChecksumRegion::all() {
+            let checksum = self.calculate_checksum(region.start(), region.end())?;
+            self.write_checksum(region, checksum)?;
         }
+        Ok(())
+    }
+
+    // Checksums are derived from the data, so they are kept out of the journal.
+    pub(crate) fn write_checksum(&mut self, region: ChecksumRegion, checksum: u8) -> Result<()> {
+        self.write_unjournaled(region.checksum_offset(), &[checksum])
+    }
 
-        current_offset = 0x6000;
-        current_write_offset = offsets::BANK3_WHOLE_CHECKSUM + 1;
+    // Compares the stored checksum of every region against its data without modifying anything.
+    pub fn verify_checksums(&self) -> Result<ChecksumReport> {
+        let mut regions = Vec::new();
 
-        for _ in 0..6 {
-            checksum = self.calculate_checksum(current_offset, current_offset + current_to_end);
-            self.write_byte(current_write_offset, checksum);
-            current_offset += offsets::BOX_NEXT_BOX;
-            current_write_offset += 1;
+        for region in ChecksumRegion::all() {
+            regions.push(RegionChecksum {
+                region,
+                stored: self.read_byte(region.checksum_offset())?,
+                computed: self.calculate_checksum(region.start(), region.end())?,
+            });
         }
 
+        Ok(ChecksumReport { regions })
     }
     
-    pub fn read_string(&self, start_offset: usize, terminator: u8) -> String {
-        let mut current_offset = start_offset;
-        let mut  current_char = self.read_byte(current_offset);
-        let mut output = String::new();
-        while current_offset < self.len() && current_char != terminator {
-            output.push(textencoding::decode(current_char));
-            current_offset += 1;
-            current_char = self.read_byte(current_offset)
+    // Reads characters until the terminator or the end of the data.
+    pub fn read_string(&self, start_offset: usize, terminator: u8) -> Result<String> {
+        let bytes = self.read_bytes(start_offset, self.len().saturating_sub(start_offset))?;
+        if bytes.is_empty() {
+            return Err(ChucksumError::OutOfBounds { offset: start_offset, len: 1, file_len: self.len() });
         }
-        output
+
+        let output = bytes.iter()
+            .take_while(|b| **b != terminator)
+            .map(|b| textencoding::decode(*b))
+            .collect();
+        Ok(output)
     }
     
-    pub fn write_string(&mut self, input: &str, start_offset: usize, terminator: u8) {
-        let mut current_offset = start_offset;
+    // Writes the encoded string followed by the terminator, cutting it short at the end of the data.
+    pub fn write_string(&mut self, input: &str, start_offset: usize, terminator: u8) -> Result<()> {
+        let mut encoded: Vec<u8> = input.chars().map(textencoding::encode).collect();
+        encoded.push(terminator);
+        encoded.truncate(self.len().saturating_sub(start_offset));
         
-        for ch in input.chars() {
-            if current_offset >= self.len() {
-                break;
-            }
-            let encoded = textencoding::encode(ch);
-            self.write_byte(current_offset, encoded);
-            current_offset += 1;
-        }
-        if current_offset < self.len() {
-            self.write_byte(current_offset, terminator);
+        if encoded.is_empty() {
+            return Ok(());
         }
+        self.write_bytes(start_offset, &encoded)
     }
     
-    pub fn bag_items_count(&self) -> u8 {
-        self.read_byte(offsets::BAG_OFFSET).into()
+    pub fn bag_items_count(&self) -> Result<u8> {
+        self.read_byte(offsets::BAG_OFFSET)
         
     }
     
-    pub fn box_items_count(&self) -> u8 {
+    pub fn box_items_count(&self) -> Result<u8> {
         self.read_byte(offsets::BOX_ITEMS_OFFSET)
     }
     
     
-    pub fn add_item(&mut self, dest: ItemStorage, item_id: u8, qty: u8) -> Result<(), BagError> {
+    pub fn add_item(&mut self, dest: ItemStorage, item_id: u8, qty: u8) -> Result<()> {
         if qty == 0 {
-            return Err(BagError::InvalidQuantity(qty))
+            return Err(BagError::InvalidQuantity(qty).into())
         }
         
         let offsets = match dest {
@@ -231,38 +533,50 @@ impl SaveFile {
                 ItemStorageOffsets {
                     offset: offsets::BAG_OFFSET,
                     max_items: offsets::MAX_BAG_ITEMS,
-                    count: self.bag_items_count()
+                    count: self.bag_items_count()?
                 }
             },
             ItemStorage::PcBox => {
                 ItemStorageOffsets {
                     offset: offsets::BOX_ITEMS_OFFSET,
                     max_items: offsets::MAX_BOX_ITEMS,
-                    count: self.box_items_count(),
+                    count: self.box_items_count()?,
                 }
                 
             }
         };
         
         if offsets.count as usize >= offsets::MAX_BOX_ITEMS {
-            return Err(BagError::BagFull);
+            return Err(BagError::BagFull.into());
         }
         
         // Check if we have a valid item id. If not display an error and abort.
         if !items::_is_valid_item(item_id) {
-            return Err(BagError::InvalidItemId(item_id));
+            return Err(BagError::InvalidItemId(item_id).into());
         }
         let next_free_slot = (offsets.offset + offsets::ITEM_LIST_FIRST_ITEM)+ (offsets::LIST_ITEM_SIZE * offsets.count as usize);
         let item_data = [item_id, qty];
         
-        self.write_bytes(next_free_slot, &item_data);
-        self.write_byte(offsets.offset, offsets.count + 1);
-        
-        Ok(())
-        
+        self.transaction(|save| {
+            save.write_bytes(next_free_slot, &item_data)?;
+            save.write_byte(offsets.offset, offsets.count + 1)
+        })
     }
     
-    pub fn list_items(&self, destination: ItemStorage) -> String {
+    // Returns the (item id, quantity) pairs in the bag or PC item box.
+    pub fn get_items(&self, storage: ItemStorage) -> Result<Vec<(u8, u8)>> {
+        let (list_offset, max) = match storage {
+            ItemStorage::Bag => (offsets::BAG_OFFSET, offsets::MAX_BAG_ITEMS),
+            ItemStorage::PcBox => (offsets::BOX_ITEMS_OFFSET, offsets::MAX_BOX_ITEMS),
+        };
+
+        let count = (self.read_byte(list_offset)? as usize).min(max);
+        let list = self.read_bytes(list_offset + offsets::ITEM_LIST_FIRST_ITEM, offsets::LIST_ITEM_SIZE * count)?;
+
+        Ok(list.chunks(offsets::LIST_ITEM_SIZE).map(|item| (item[0], item[1])).collect())
+    }
+    
+    pub fn list_items(&self, destination: ItemStorage) -> Result<String> {
         let mut output = String::new();
         let mut current_slot = 0;
         
@@ -273,14 +587,14 @@ impl SaveFile {
                 ItemStorageOffsets {
                     max_items: offsets::MAX_BAG_ITEMS,
                     offset : offsets::BAG_OFFSET,
-                    count: self.bag_items_count()
+                    count: self.bag_items_count()?
                 }
             }
             ItemStorage::PcBox => {
                 ItemStorageOffsets {
                     max_items: offsets::MAX_BOX_ITEMS,
                     offset: offsets::BOX_ITEMS_OFFSET,
-                    count: self.box_items_count()
+                    count: self.box_items_count()?
                 }
             }
         };
@@ -291,8 +605,8 @@ impl SaveFile {
             let last_slot_offset = current_offset + (offsets::LIST_ITEM_SIZE * dest_offsets.max_items);
             
             while current_offset <= last_slot_offset && current_slot < dest_offsets.count  {
-                let current_item = items::get_item_name(self.read_byte(current_offset));
-                let item_qty = self.read_byte(current_offset + 1);
+                let current_item = items::get_item_name(self.read_byte(current_offset)?);
+                let item_qty = self.read_byte(current_offset + 1)?;
                 
                 output.push_str(format!("{current_item} - Qty: {item_qty}\n").as_str());
                 
@@ -301,22 +615,22 @@ impl SaveFile {
             }
             
         }
-        output
+        Ok(output)
     }
     
-    pub fn set_player_name(&mut self, input: &str) {
-        self.write_string(input, offsets::PLAYER_NAME_OFFSET, 0x50);
+    pub fn set_player_name(&mut self, input: &str) -> Result<()> {
+        self.write_string(input, offsets::PLAYER_NAME_OFFSET, 0x50)
     }
     
-    pub fn get_player_name(&self) -> String {
+    pub fn get_player_name(&self) -> Result<String> {
         self.read_string(offsets::PLAYER_NAME_OFFSET, 0x50)
     }
     
-    pub fn set_rival_name(&mut self, input: &str) {
-        self.write_string(input, offsets::RIVAL_NAME_OFFSET, offsets::NAME_TERMINATOR);
+    pub fn set_rival_name(&mut self, input: &str) -> Result<()> {
+        self.write_string(input, offsets::RIVAL_NAME_OFFSET, offsets::NAME_TERMINATOR)
     }
     
-    pub fn get_rival_name(&self) -> String {
+    pub fn get_rival_name(&self) -> Result<String> {
         self.read_string(offsets::RIVAL_NAME_OFFSET, offsets::NAME_TERMINATOR)
     }
     
@@ -335,18 +649,14 @@ impl SaveFile {
     
     
     
-    pub fn get_money(&self) -> u32 {
-        let offset = offsets::MONEY_OFFSET;
+    pub fn get_money(&self) -> Result<u32> {
+        let bytes = self.read_bytes(offsets::MONEY_OFFSET, 3)?;
         
-        let b1 = self.data[offset];
-        let b2 = self.data[offset + 1];
-        let b3 = self.data[offset + 2];
+        let d1 = Self::_bcd_byte_to_decimal(bytes[0]) as u32;
+        let d2 = Self::_bcd_byte_to_decimal(bytes[1]) as u32;
+        let d3 = Self::_bcd_byte_to_decimal(bytes[2]) as u32;
         
-        let d1 = Self::_bcd_byte_to_decimal(b1) as u32;
-        let d2 = Self::_bcd_byte_to_decimal(b2) as u32;
-        let d3 = Self::_bcd_byte_to_decimal(b3) as u32;
-        
-        d1 * 10_000 + d2 * 100 + d3
+        Ok(d1 * 10_000 + d2 * 100 + d3)
     }
     
     fn _money_to_bcd_bytes(mut money: u32) -> [u8; 3] {
@@ -367,80 +677,127 @@ impl SaveFile {
         ]
     }
     
-    pub fn set_money(&mut self, money: u32) {
+    pub fn set_money(&mut self, money: u32) -> Result<()> {
         let bytes = Self::_money_to_bcd_bytes(money);
-        self.write_bytes(offsets::MONEY_OFFSET, &bytes);
+        self.write_bytes(offsets::MONEY_OFFSET, &bytes)
     }
     
-    pub fn get_party_species_names(&self) -> Result<Vec<&'static str>, PokemonError> {
+    pub fn get_party_species_names(&self) -> Result<Vec<&'static str>> {
         
-        let count = self.read_byte(offsets::PARTY_DATA_OFFSET);
-        if count <= 0 || count > 6 {
-            return Err(PokemonError::InvalidData);
+        let count = self.read_byte(offsets::PARTY_DATA_OFFSET)?;
+        if count == 0 || count > 6 {
+            return Err(PokemonError::InvalidData.into());
         }
-        let mut species_names: Vec<&'static str> = Vec::new();
-        let count = self.read_byte(offsets::PARTY_DATA_OFFSET);
         let current_offset = offsets::PARTY_DATA_OFFSET + offsets::PARTY_SPECIES_LIST_OFFSET;
-        for i in 0..count as usize {
-            let species_id = self.read_byte(current_offset + i);
-            species_names.push(Pokemon::get_species_name(species_id));
-        }
-        if species_names.len() == 0 || species_names.len() > 6 {
-            return Err(PokemonError::InvalidData);
-        }
+        let species_names = self.read_bytes(current_offset, count as usize)?
+            .iter()
+            .map(|species_id| Pokemon::get_species_name(*species_id))
+            .collect();
         Ok(species_names)
     }
     
-    pub fn get_party_count(&self) -> usize {
-        self.read_byte(offsets::PARTY_DATA_OFFSET) as usize
+    pub fn get_party_count(&self) -> Result<usize> {
+        Ok(self.read_byte(offsets::PARTY_DATA_OFFSET)? as usize)
     }
     
-    pub fn get_current_box_pokemon_count(&self) -> usize {
-        self.read_byte(offsets::BOX_CURRENT_DATA_OFFSET) as usize
+    pub fn get_current_box_pokemon_count(&self) -> Result<usize> {
+        Ok(self.read_byte(offsets::BOX_CURRENT_DATA_OFFSET)? as usize)
     }
     
-    pub fn get_box_pokemon_count(&self, box_number: usize) -> usize {
+    pub fn get_box_pokemon_count(&self, box_number: usize) -> Result<usize> {
 
-        if box_number == self.get_current_box() {
-            let offset = offsets::PARTY_DATA_OFFSET;
-            return self.read_byte(offset) as usize;
+        if !(1..=offsets::NUM_POKEMON_BOXES).contains(&box_number) {
+            return Ok(0);
         }
-        
-        if box_number > 0 && box_number <= 6 {
-            let offset = offsets::BOX_1_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 1));
-            return self.read_byte(offset) as usize;
+        Ok(self.read_byte(self.get_box_pokemon_count_offset(box_number)?)? as usize)
+    }
+    
+    // Offset of the first byte (the pokemon count) of the box data the game will actually use.
+    // The current box is edited in a working copy in the main data block and only written back
+    // to its bank when the player switches boxes, so its bank slot is stale until then.
+    pub fn get_box_pokemon_count_offset(&self, box_number: usize) -> Result<usize> {
+        if box_number == self.get_current_box()? {
+            Ok(offsets::BOX_CURRENT_DATA_OFFSET)
         }
-        else if box_number > 6 && box_number <= 12 {
-            let offset = offsets::BOX_7_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 7));
-            return self.read_byte(offset) as usize;
+        else {
+            Ok(Self::box_data_offset(box_number))
         }
-        return 0;
     }
     
-    pub fn get_box_pokemon_count_offset(&self, box_number: usize) -> usize {
-        if box_number == self.get_current_box() {
-            return offsets::PARTY_DATA_OFFSET;
-        }
-        else if box_number <= 6 {
-            return offsets::BOX_1_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 1))
+    // Offset of the first byte (the pokemon count) of a box's data within its SRAM bank.
+    pub fn box_data_offset(box_number: usize) -> usize {
+        if box_number <= 6 {
+            offsets::BOX_1_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 1))
         }
         else {
-            return offsets::BOX_7_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 7));
+            offsets::BOX_7_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 7))
         }
     }
     
-    pub fn get_party_pokemon_data(&self) -> Result<Vec<Pokemon>, PokemonError> {
-        let count = self.get_party_count();
+    // Returns where the count, species list, data structures and names of a pokemon list are stored.
+    pub fn pokemon_list_layout(&self, storage: PokemonStorage) -> Result<PokemonListLayout> {
+        let layout = match storage {
+            PokemonStorage::Party => PokemonListLayout {
+                count_offset: offsets::PARTY_DATA_OFFSET,
+                capacity: offsets::MAX_PARTY_SIZE,
+                first_pokemon: offsets::PARTY_FIRST_PKMN,
+                pokemon_size: offsets::PARTY_NEXT_PKMN,
+                first_ot: offsets::PARTY_FIRST_OT,
+                first_nick: offsets::PARTY_FIRST_NICK,
+            },
+            PokemonStorage::PcBox(box_number) => {
+                let base = self.get_box_pokemon_count_offset(box_number)?;
+                PokemonListLayout {
+                    count_offset: base,
+                    capacity: offsets::MAX_POKEMON_BOX_SIZE,
+                    first_pokemon: base + offsets::BOX_START_TO_FIRST,
+                    pokemon_size: offsets::BOX_NEXT_PKMN,
+                    first_ot: base + offsets::BOX_FIRST_OT,
+                    first_nick: base + offsets::BOX_FIRST_NICK,
+                }
+            }
+        };
+        Ok(layout)
+    }
+    
+    // Reads every pokemon in the party or a box along with its nickname and OT name.
+    pub fn get_stored_pokemon(&self, storage: PokemonStorage) -> Result<Vec<StoredPokemon>> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let count = self.read_byte(layout.count_offset)? as usize;
+        if count > layout.capacity {
+            return Err(PokemonError::InvalidData.into());
+        }
+
+        let mut list = Vec::new();
+        for index in 0..count {
+            let offset = layout.first_pokemon + layout.pokemon_size * index;
+            let raw = match storage {
+                PokemonStorage::Party => self.read_party_pokemon_raw(offset)?,
+                PokemonStorage::PcBox(_) => self.read_box_pokemon_raw(offset)?,
+            };
+
+            list.push(StoredPokemon {
+                location: PokemonLocation { storage, slot: index + 1 },
+                pokemon: Pokemon::from_raw(raw),
+                nickname: self.read_string(layout.first_nick + offsets::PARTY_OT_NICK_SIZE * index, offsets::NAME_TERMINATOR)?,
+                ot_name: self.read_string(layout.first_ot + offsets::PARTY_OT_NICK_SIZE * index, offsets::NAME_TERMINATOR)?,
+            });
+        }
+        Ok(list)
+    }
+
+    pub fn get_party_pokemon_data(&self) -> Result<Vec<Pokemon>> {
+        let count = self.get_party_count()?;
         
-        if count <= 0 || count > offsets::MAX_PARTY_SIZE {
-            return Err(PokemonError::InvalidData);
+        if count == 0 || count > offsets::MAX_PARTY_SIZE {
+            return Err(PokemonError::InvalidData.into());
         }
         
         let mut offset = offsets::PARTY_FIRST_PKMN;
         let mut list = Vec::new();
         
         for _ in 0..count {
-            let raw = self.read_party_pokemon_raw(offset);
+            let raw = self.read_party_pokemon_raw(offset)?;
             let pokemon = Pokemon::from_raw(raw);
             list.push(pokemon);
             offset += offsets::PARTY_NEXT_PKMN;
@@ -450,18 +807,18 @@ impl SaveFile {
     }
     
     
-    pub fn get_current_box_pokemon_data(&self) -> Result<Vec<Pokemon>, PokemonError> {
-        let count = self.get_current_box_pokemon_count();
+    pub fn get_current_box_pokemon_data(&self) -> Result<Vec<Pokemon>> {
+        let count = self.get_current_box_pokemon_count()?;
         
-        if count <= 0 || count > offsets::MAX_POKEMON_BOX_SIZE {
-            return Err(PokemonError::LookupError);
+        if count == 0 || count > offsets::MAX_POKEMON_BOX_SIZE {
+            return Err(PokemonError::LookupError.into());
         }
         
         let mut offset = offsets::BOX_CURRENT_FIRST_PKMN;
         let mut list = Vec::new();
         
         for _ in 0..count {
-            let raw = self.read_box_pokemon_raw(offset);
+            let raw = self.read_box_pokemon_raw(offset)?;
             let pokemon = Pokemon::from_raw(raw);
             list.push(pokemon);
             offset += offsets::BOX_NEXT_PKMN;
@@ -469,32 +826,23 @@ impl SaveFile {
         Ok(list)
     }
     
-    pub fn get_box_pokemon_data(&self, box_number: usize) -> Result<Vec<Pokemon>, PokemonError> {
+    pub fn get_box_pokemon_data(&self, box_number: usize) -> Result<Vec<Pokemon>> {
         
-        if box_number < 1 || box_number > 12 {
-            return Err(PokemonError::InvalidBoxNumber);
+        if !(1..=12).contains(&box_number) {
+            return Err(PokemonError::InvalidBoxNumber.into());
         }
-        let count = self.get_box_pokemon_count(box_number);
+        let count = self.get_box_pokemon_count(box_number)?;
         let mut list = Vec::new();
         
         // Set offset to the first byte in the box structure
-        // Handle box 1-6:
-        let mut offset: usize;
-        if box_number <= 6 {
-            offset = offsets::BOX_1_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 1));
-            
-        }
-        // Handle box 7-12
-        else {
-            offset = offsets::BOX_7_DATA_OFFSET + (offsets::BOX_NEXT_BOX * (box_number - 7));
-        }
+        let mut offset = self.get_box_pokemon_count_offset(box_number)?;
         
         // Skip to begining of first pokemon's data
         offset += offsets::BOX_START_TO_FIRST; 
         
         
         for _ in 0..count {
-            let raw = self.read_box_pokemon_raw(offset);
+            let raw = self.read_box_pokemon_raw(offset)?;
             let pokemon = Pokemon::from_raw(raw);
             list.push(pokemon);
             offset += offsets::BOX_NEXT_PKMN;
@@ -503,28 +851,28 @@ impl SaveFile {
         Ok(list)
     }
     
-    pub fn read_party_pokemon_raw(&self, offset: usize) -> PokemonRaw {
+    pub fn read_party_pokemon_raw(&self, offset: usize) -> Result<PokemonRaw> {
         let mut data = [0u8; offsets::PARTY_NEXT_PKMN];
-        data.copy_from_slice(&self.data[offset..offset + offsets::PARTY_NEXT_PKMN]);
-        PokemonRaw::new(data)
+        data.copy_from_slice(self.read_bytes(offset, offsets::PARTY_NEXT_PKMN)?);
+        Ok(PokemonRaw::new(data))
     }
     
-    pub fn read_box_pokemon_raw(&self, offset: usize) -> PokemonRaw {
+    pub fn read_box_pokemon_raw(&self, offset: usize) -> Result<PokemonRaw> {
         let mut data = [0u8; offsets::PARTY_NEXT_PKMN];
-        data[..offsets::BOX_NEXT_PKMN].copy_from_slice(&self.data[offset..offset + offsets::BOX_NEXT_PKMN]);
-        data[0x21] = self.data[offset + 0x03];
+        data[..offsets::BOX_NEXT_PKMN].copy_from_slice(self.read_bytes(offset, offsets::BOX_NEXT_PKMN)?);
+        data[0x21] = data[0x03];
         
         
-        PokemonRaw::new(data)
+        Ok(PokemonRaw::new(data))
         
     }
     
-    pub fn get_badges(&self) -> Badges {
-        Badges::from_bits_truncate(self.read_byte(offsets::BADGES))
+    pub fn get_badges(&self) -> Result<Badges> {
+        Ok(Badges::from_bits_truncate(self.read_byte(offsets::BADGES)?))
     }
     
-    pub fn badges_strings(&self) -> Result<Vec<&'static str>, PokemonError> {
-        let b = self.get_badges();
+    pub fn badges_strings(&self) -> Result<Vec<&'static str>> {
+        let b = self.get_badges()?;
         // let b = Badges::from_bits_truncate(0b0010_1111);
         let names = [
         (Badges::BOULDER, "Boulder"),
@@ -545,40 +893,34 @@ impl SaveFile {
         }
         
         if collected.is_empty() {
-            Err(PokemonError::LookupError)
+            Err(PokemonError::LookupError.into())
         } else {
             Ok(collected)
         }
     }
     
-    pub fn get_player_id(&self) -> u16 {
+    pub fn get_player_id(&self) -> Result<u16> {
         self.read_u16_be(offsets::PLAYER_ID)
     }
 
     // Function to ensure parameters passed to copy_party_pokemon() are valid
     // and that the copy operation will be a success prior to making any changes
     // to the save data.
-    fn validate_copy_pokemon_operation(&self, party_slot: usize, box_number: usize) -> Result<(), PokemonError> {
+    fn validate_copy_pokemon_operation(&self, party_slot: usize, box_number: usize) -> Result<()> {
 
-        if party_slot <= 0 || party_slot > offsets::MAX_PARTY_SIZE {
-            return Err(PokemonError::InvalidPartySlot);
+        if party_slot == 0 || party_slot > offsets::MAX_PARTY_SIZE {
+            return Err(PokemonError::InvalidPartySlot.into());
         }        
-        if box_number <= 0 || box_number > offsets::NUM_POKEMON_BOXES {
-            return Err(PokemonError::InvalidBoxNumber);
-        }
-
-        // Putting pokemon in the current box doesn't work correctly since the game keeps current box
-        // data in a temporary location and changes here will get overwritten when interacting with the PC
-        if box_number == self.get_current_box() {
-            return Err(PokemonError::BoxInUse);
+        if box_number == 0 || box_number > offsets::NUM_POKEMON_BOXES {
+            return Err(PokemonError::InvalidBoxNumber.into());
         }
 
-        if !self.box_has_free_slot(box_number) {
-            return Err(PokemonError::PokemonBoxFull);
+        if !self.box_has_free_slot(box_number)? {
+            return Err(PokemonError::PokemonBoxFull.into());
         }
 
-        if !self.is_valid_party_slot(party_slot) {
-            return Err(PokemonError::InvalidPartySlot);
+        if !self.is_valid_party_slot(party_slot)? {
+            return Err(PokemonError::InvalidPartySlot.into());
         }
 
 
@@ -586,102 +928,363 @@ impl SaveFile {
     }
 
     // Returns true if given box number has at least one free space in it
-    fn box_has_free_slot(&self, box_number: usize) -> bool {
+    fn box_has_free_slot(&self, box_number: usize) -> Result<bool> {
 
         
         // If box number is not valid, return false
-        if box_number < 1 || box_number > 12 {
-            return false;
+        if !(1..=12).contains(&box_number) {
+            return Ok(false);
         }
 
-        if self.get_current_box() == box_number {
-            return self.get_current_box_pokemon_count() < offsets::MAX_POKEMON_BOX_SIZE;
-        }
-        self.get_box_pokemon_count(box_number) < 20
+        Ok(self.get_box_pokemon_count(box_number)? < offsets::MAX_POKEMON_BOX_SIZE)
     }
 
-    fn is_valid_party_slot(&self, party_slot: usize) -> bool {
+    fn is_valid_party_slot(&self, party_slot: usize) -> Result<bool> {
         // Ensure the party slot passed in is valid and has a pokemone in it, otherwise error and abort
-        party_slot <= self.get_party_count()
+        Ok(party_slot <= self.get_party_count()?)
     }
     
     // This function is able to clone a party pokemon and deposit it in a PC pokemon box.
     // The operation involves copying the main pokemon data structure, the nickname, and OT name
     // of the pokemon to a PC box.
-    pub fn copy_party_pokemon(&mut self, party_slot: usize, box_number: usize) -> Result<(), PokemonError> {
+    pub fn copy_party_pokemon(&mut self, party_slot: usize, box_number: usize) -> Result<()> {
         
         // Check parameters passed in are valid and the box 
         // has a free slot. Throw an error and abort the operation 
         // if the validation fails.
-        if let Err(e) = self.validate_copy_pokemon_operation(party_slot, box_number) {
-            return Err(e);
-        }
+        self.validate_copy_pokemon_operation(party_slot, box_number)?;
                 
         // Copy the pokemon's data from the party as a PokemonRaw object
         // The last line converts the pokmeon data from the 44 byte party structure to the 33 byte box structure we 
-        // need to move it from party to box.
+        // need to move it from party to box. The box level byte is brought up to date on the way, like the game does.
         let party_pokemon_offset = offsets::PARTY_FIRST_PKMN + (offsets::PARTY_NEXT_PKMN * (party_slot - 1));       
-        let raw_pokemon = self.read_party_pokemon_raw(party_pokemon_offset);
+        let raw_pokemon = Pokemon::from_raw(self.read_party_pokemon_raw(party_pokemon_offset)?).to_box_raw();
         let data = raw_pokemon.get_for_box();
         let species_id = data[0];
         
 
-        let mut box_base_offset ;
         // Determine detination PC box offset to write copied data. 
-        // If box 1-6 (bank2), start at the begin of bank 2
-        // If box 7-12 (bank3), start at the begin of bank 3.
-        // then move the offset forward to the start of the selected box within bank 1 or 2.
-        if box_number <= 6 {
-            box_base_offset = offsets::BOX_1_DATA_OFFSET;
-            box_base_offset += offsets::BOX_NEXT_BOX * (box_number - 1);
-        }
-        else {
-            box_base_offset = offsets::BOX_7_DATA_OFFSET;
-            box_base_offset += offsets::BOX_NEXT_BOX * (box_number - 7);
-        }
+        // Boxes 1-6 live in bank 2 and boxes 7-12 in bank 3, the current box in its working copy.
+        let mut box_base_offset = self.get_box_pokemon_count_offset(box_number)?;
         
         // Next we set asside the destination offsets that the pokemon's OT and nick name will be written to.
         // OT and nick name data are not stored in the main pokemon data structure and are written seperately.
-        let box_count = self.get_box_pokemon_count(box_number);
+        let box_count = self.get_box_pokemon_count(box_number)?;
         let ot_destination_offset = box_base_offset + offsets::BOX_FIRST_OT + (offsets::PARTY_OT_NICK_SIZE * box_count);
         let nick_destination_offset = box_base_offset + offsets::BOX_FIRST_NICK + (offsets::PARTY_OT_NICK_SIZE * box_count);
 
         // Here we copy the current pokemon's OT and nick name from party data so we can copy them to the box.
         let ot_source_offset = offsets::PARTY_FIRST_OT + (offsets::PARTY_OT_NICK_SIZE * (party_slot - 1));
-        let ot_name = self.read_string(ot_source_offset, 0x50);
+        let ot_name = self.read_string(ot_source_offset, 0x50)?;
         let nick_source_offset = offsets::PARTY_FIRST_NICK + (offsets::PARTY_OT_NICK_SIZE * (party_slot - 1));
-        let nick_name = self.read_string(nick_source_offset, offsets::NAME_TERMINATOR);
+        let nick_name = self.read_string(nick_source_offset, offsets::NAME_TERMINATOR)?;
         
         // Lastly, move the current offset forward to the first empty slot in the destination box. We're ready to write the main
         // pokmeon data here next.
         box_base_offset += offsets::BOX_START_TO_FIRST;
         box_base_offset += offsets::BOX_NEXT_PKMN * box_count;
         
-        // Write 33 byte pokemon structure to PC box (Main pokemon data w/o nick and OT)
-        self.write_bytes(box_base_offset, &data);
+        // Everything from here on is written as one transaction. If any write fails the box is left as it was.
+        self.transaction(|save| {
+            // Write 33 byte pokemon structure to PC box (Main pokemon data w/o nick and OT)
+            save.write_bytes(box_base_offset, data)?;
 
-        // Next we need to update the box count by 1 so the game knows we inserted a pokemon
-        let count_update_offset = self.get_box_pokemon_count_offset(box_number);
-        self.write_byte(count_update_offset, (box_count + 1) as u8);
+            // Next we need to update the box count by 1 so the game knows we inserted a pokemon
+            let count_update_offset = save.get_box_pokemon_count_offset(box_number)?;
+            save.write_byte(count_update_offset, (box_count + 1) as u8)?;
 
-        // The beginning of a pokemon list is a list of the species ID's of the pokemon in the box. Here we're
-        // inserting the pokemon we added to the box's species ID to the end of that list and a list terminator character 0xFF
-        let species_update_offset = count_update_offset + box_count + 1;
-        let species_data = &[species_id, 0xFF];
-        self.write_bytes(species_update_offset, species_data);
-        
-        // Finally, write the nickname and OT strings to the PC box. This data is kept seperately from the pokemon's main data
-        // structure
-        self.write_string(&ot_name, ot_destination_offset, offsets::NAME_TERMINATOR);
-        self.write_string(&nick_name, nick_destination_offset, offsets::NAME_TERMINATOR);
-        
-        // Ok all finished! Remember, must call .save() on the SaveFile so all the checksums get updated!!!
-        Ok(())
+            // The beginning of a pokemon list is a list of the species ID's of the pokemon in the box. Here we're
+            // inserting the pokemon we added to the box's species ID to the end of that list and a list terminator character 0xFF
+            let species_update_offset = count_update_offset + box_count + 1;
+            let species_data = &[species_id, 0xFF];
+            save.write_bytes(species_update_offset, species_data)?;
+
+            // Finally, write the nickname and OT strings to the PC box. This data is kept seperately from the pokemon's main data
+            // structure
+            save.write_string(&ot_name, ot_destination_offset, offsets::NAME_TERMINATOR)?;
+            save.write_string(&nick_name, nick_destination_offset, offsets::NAME_TERMINATOR)?;
+
+            // Ok all finished! Remember, must call .save() on the SaveFile so all the checksums get updated!!!
+            Ok(())
+        })
     }
 
 
-    pub fn get_current_box(&self) -> usize {
-        ((self.read_byte(offsets::CURRENT_BOX) & 0x7F) + 1) as usize
+    // Moves a party pokemon into a PC box, like depositing it in game. Either the box gains it
+    // and the party loses it, or neither changes.
+    // As in game, the last pokemon in the party can't be deposited.
+    pub fn deposit_party_pokemon(&mut self, party_slot: usize, box_number: usize) -> Result<()> {
+        self.validate_copy_pokemon_operation(party_slot, box_number)?;
+        if self.get_party_count()? == 1 {
+            return Err(PokemonError::LastPartyPokemon.into());
+        }
+
+        // Names are moved as raw bytes so characters the text encoding doesn't know survive
+        let offset = self.pokemon_offset(PokemonLocation { storage: PokemonStorage::Party, slot: party_slot })?;
+        let pokemon = Pokemon::from_raw(self.read_party_pokemon_raw(offset)?);
+        let mut entry = self.read_list_entry(PokemonStorage::Party, party_slot)?;
+        entry.data = pokemon.to_box_raw().get_for_box().to_vec();
+
+        self.transaction(|save| {
+            save.append_list_entry(PokemonStorage::PcBox(box_number), &entry)?;
+            save.remove_list_entry(PokemonStorage::Party, party_slot)
+        })
+    }
+
+    // Overwrites the pokemon in a party slot (1-6) with `pokemon`. The slot must already be occupied.
+    // The species list entry is updated along with the data structure so the two stay in sync.
+    pub fn set_party_pokemon(&mut self, party_slot: usize, pokemon: &Pokemon) -> Result<()> {
+        if party_slot == 0 || party_slot > self.get_party_count()?.min(offsets::MAX_PARTY_SIZE) {
+            return Err(PokemonError::InvalidPartySlot.into());
+        }
+
+        let raw = pokemon.to_raw();
+        self.write_pokemon(PokemonStorage::Party, party_slot, raw.get_for_party())
+    }
+
+    // Overwrites the pokemon in a box slot (1-20) with `pokemon`. The slot must already be occupied.
+    // Boxed pokemon have no stored stats, so max HP and the other stats are not written.
+    pub fn set_box_pokemon(&mut self, box_number: usize, box_slot: usize, pokemon: &Pokemon) -> Result<()> {
+        if !(1..=offsets::NUM_POKEMON_BOXES).contains(&box_number) {
+            return Err(PokemonError::InvalidBoxNumber.into());
+        }
+        if box_slot == 0 || box_slot > self.get_box_pokemon_count(box_number)?.min(offsets::MAX_POKEMON_BOX_SIZE) {
+            return Err(PokemonError::InvalidBoxSlot.into());
+        }
+
+        let raw = pokemon.to_box_raw();
+        self.write_pokemon(PokemonStorage::PcBox(box_number), box_slot, raw.get_for_box())
+    }
+
+    // Writes the four DVs of a party or box pokemon into their two packed bytes.
+    pub fn set_dvs(&mut self, location: PokemonLocation, dvs: Dvs) -> Result<()> {
+        if !dvs.is_valid() {
+            return Err(PokemonError::InvalidDv.into());
+        }
+        let offset = self.pokemon_offset(location)?;
+        self.write_bytes(offset + offsets::PARTY_IV_1, &dvs.to_bytes())
+    }
+
+    // Writes the status byte of a party or box pokemon. Status::empty() cures it.
+    pub fn set_status(&mut self, location: PokemonLocation, status: Status) -> Result<()> {
+        let offset = self.pokemon_offset(location)?;
+        self.write_bytes(offset + offsets::PARTY_STATUS, &[status.bits()])
+    }
+
+    // Offset of the data structure of an occupied party or box slot.
+    fn pokemon_offset(&self, location: PokemonLocation) -> Result<usize> {
+        let slot_error = match location.storage {
+            PokemonStorage::Party => PokemonError::InvalidPartySlot,
+            PokemonStorage::PcBox(box_number) if (1..=offsets::NUM_POKEMON_BOXES).contains(&box_number) => PokemonError::InvalidBoxSlot,
+            PokemonStorage::PcBox(_) => return Err(PokemonError::InvalidBoxNumber.into()),
+        };
+
+        let layout = self.pokemon_list_layout(location.storage)?;
+        let count = (self.read_byte(layout.count_offset)? as usize).min(layout.capacity);
+        if location.slot == 0 || location.slot > count {
+            return Err(slot_error.into());
+        }
+        Ok(layout.first_pokemon + layout.pokemon_size * (location.slot - 1))
+    }
+
+    // Moves a pokemon from a box into the party, like withdrawing it from the PC. Its stats are
+    // recalculated for the 44 byte party structure and the box is closed up behind it.
+    pub fn withdraw_box_pokemon(&mut self, box_number: usize, box_slot: usize) -> Result<()> {
+        let storage = PokemonStorage::PcBox(box_number);
+        let offset = self.pokemon_offset(PokemonLocation { storage, slot: box_slot })?;
+        if self.get_party_count()? >= offsets::MAX_PARTY_SIZE {
+            return Err(PokemonError::PartyFull.into());
+        }
+
+        let mut pokemon = Pokemon::from_raw(self.read_box_pokemon_raw(offset)?);
+        pokemon.recalculate_stats()?;
+
+        let mut entry = self.read_list_entry(storage, box_slot)?;
+        entry.data = pokemon.to_raw().get_for_party().to_vec();
+
+        self.transaction(|save| {
+            save.append_list_entry(PokemonStorage::Party, &entry)?;
+            save.remove_list_entry(storage, box_slot)
+        })
+    }
+
+    // Removes a pokemon from the party for good. The party can't be left empty.
+    pub fn release_party_pokemon(&mut self, party_slot: usize) -> Result<()> {
+        self.pokemon_offset(PokemonLocation { storage: PokemonStorage::Party, slot: party_slot })?;
+        if self.get_party_count()? == 1 {
+            return Err(PokemonError::LastPartyPokemon.into());
+        }
+
+        self.transaction(|save| save.remove_list_entry(PokemonStorage::Party, party_slot))
+    }
+
+    // Removes a pokemon from a box for good.
+    pub fn release_box_pokemon(&mut self, box_number: usize, box_slot: usize) -> Result<()> {
+        let storage = PokemonStorage::PcBox(box_number);
+        self.pokemon_offset(PokemonLocation { storage, slot: box_slot })?;
+
+        self.transaction(|save| save.remove_list_entry(storage, box_slot))
+    }
+
+    // Swaps two pokemon in the party or the same box, names included.
+    pub fn swap_pokemon(&mut self, storage: PokemonStorage, slot_a: usize, slot_b: usize) -> Result<()> {
+        self.pokemon_offset(PokemonLocation { storage, slot: slot_a })?;
+        self.pokemon_offset(PokemonLocation { storage, slot: slot_b })?;
+
+        let entry_a = self.read_list_entry(storage, slot_a)?;
+        let entry_b = self.read_list_entry(storage, slot_b)?;
+        self.transaction(|save| {
+            save.write_list_entry(storage, slot_a, &entry_b)?;
+            save.write_list_entry(storage, slot_b, &entry_a)
+        })
+    }
+
+    // Moves a pokemon to another slot of the same list, shifting the pokemon in between
+    // along by one. Moving a pokemon to slot 1 makes it the lead of the party.
+    pub fn move_pokemon_to_slot(&mut self, storage: PokemonStorage, from_slot: usize, to_slot: usize) -> Result<()> {
+        self.pokemon_offset(PokemonLocation { storage, slot: from_slot })?;
+        self.pokemon_offset(PokemonLocation { storage, slot: to_slot })?;
+
+        let first = from_slot.min(to_slot);
+        let mut entries = (first..=from_slot.max(to_slot))
+            .map(|slot| self.read_list_entry(storage, slot))
+            .collect::<Result<Vec<_>>>()?;
+        if from_slot < to_slot {
+            entries.rotate_left(1);
+        } else {
+            entries.rotate_right(1);
+        }
+
+        self.transaction(|save| {
+            for (i, entry) in entries.iter().enumerate() {
+                save.write_list_entry(storage, first + i, entry)?;
+            }
+            Ok(())
+        })
+    }
+
+    // Moves a pokemon to the end of another box. Boxes 1-6 and 7-12 are in different SRAM banks,
+    // which only changes where the data is written. Moving it into its own box puts it last.
+    pub fn move_box_pokemon(&mut self, src_box: usize, src_slot: usize, dst_box: usize) -> Result<()> {
+        self.move_box_pokemon_selection(src_box, &[src_slot], dst_box)
+    }
+
+    // Moves several pokemon from one box to the end of another, keeping their order. Either all
+    // of them are moved or, if the destination box runs out of room, none are.
+    pub fn move_box_pokemon_selection(&mut self, src_box: usize, src_slots: &[usize], dst_box: usize) -> Result<()> {
+        let src = PokemonStorage::PcBox(src_box);
+        let dst = PokemonStorage::PcBox(dst_box);
+        if ![src_box, dst_box].iter().all(|b| (1..=offsets::NUM_POKEMON_BOXES).contains(b)) {
+            return Err(PokemonError::InvalidBoxNumber.into());
+        }
+
+        let mut slots = src_slots.to_vec();
+        slots.sort_unstable();
+        slots.dedup();
+        let entries = slots.iter()
+            .map(|slot| {
+                self.pokemon_offset(PokemonLocation { storage: src, slot: *slot })?;
+                self.read_list_entry(src, *slot)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.transaction(|save| {
+            for slot in slots.iter().rev() {
+                save.remove_list_entry(src, *slot)?;
+            }
+            for entry in &entries {
+                save.append_list_entry(dst, entry)?;
+            }
+            Ok(())
+        })
+    }
+
+    // Moves every pokemon in `src_box` to the end of `dst_box`.
+    pub fn move_all_box_pokemon(&mut self, src_box: usize, dst_box: usize) -> Result<()> {
+        let count = self.get_box_pokemon_count(src_box)?.min(offsets::MAX_POKEMON_BOX_SIZE);
+        let slots: Vec<usize> = (1..=count).collect();
+        self.move_box_pokemon_selection(src_box, &slots, dst_box)
+    }
+
+    fn read_list_entry(&self, storage: PokemonStorage, slot: usize) -> Result<ListEntry> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let name_offset = offsets::PARTY_OT_NICK_SIZE * (slot - 1);
+
+        Ok(ListEntry {
+            data: self.read_bytes(layout.first_pokemon + layout.pokemon_size * (slot - 1), layout.pokemon_size)?.to_vec(),
+            ot_name: self.read_bytes(layout.first_ot + name_offset, offsets::PARTY_OT_NICK_SIZE)?.to_vec(),
+            nickname: self.read_bytes(layout.first_nick + name_offset, offsets::PARTY_OT_NICK_SIZE)?.to_vec(),
+        })
+    }
+
+    // Writes an entry over an occupied slot, keeping its species list entry in step.
+    fn write_list_entry(&mut self, storage: PokemonStorage, slot: usize, entry: &ListEntry) -> Result<()> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let name_offset = offsets::PARTY_OT_NICK_SIZE * (slot - 1);
+
+        self.write_pokemon(storage, slot, &entry.data)?;
+        self.write_bytes(layout.first_ot + name_offset, &entry.ot_name)?;
+        self.write_bytes(layout.first_nick + name_offset, &entry.nickname)
+    }
+
+    // Adds an entry after the last pokemon in a list. `entry.data` must already be the right size for the list.
+    fn append_list_entry(&mut self, storage: PokemonStorage, entry: &ListEntry) -> Result<()> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let count = self.read_byte(layout.count_offset)? as usize;
+        if count >= layout.capacity {
+            return Err(match storage {
+                PokemonStorage::Party => PokemonError::PartyFull,
+                PokemonStorage::PcBox(_) => PokemonError::PokemonBoxFull,
+            }.into());
+        }
+
+        self.write_byte(layout.count_offset, (count + 1) as u8)?;
+        self.write_byte(layout.count_offset + count + 2, offsets::PARTY_LIST_TERMINATOR)?;
+        self.write_list_entry(storage, count + 1, entry)
+    }
+
+    // Removes the pokemon in `slot` and moves every pokemon after it up one slot, the way the game
+    // does. The old last slot keeps its stale bytes, as it does in game.
+    fn remove_list_entry(&mut self, storage: PokemonStorage, slot: usize) -> Result<()> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let count = self.read_byte(layout.count_offset)? as usize;
+        // A corrupt count would shift entries past the last slot into the name tables
+        if count > layout.capacity {
+            return Err(PokemonError::InvalidData.into());
+        }
+
+        for next in slot + 1..=count {
+            let entry = self.read_list_entry(storage, next)?;
+            self.write_list_entry(storage, next - 1, &entry)?;
+        }
+        self.write_byte(layout.count_offset + count, offsets::PARTY_LIST_TERMINATOR)?;
+        self.write_byte(layout.count_offset, (count - 1) as u8)
+    }
+
+    // Writes a party or box data structure and its species list entry as one edit.
+    fn write_pokemon(&mut self, storage: PokemonStorage, slot: usize, data: &[u8]) -> Result<()> {
+        let layout = self.pokemon_list_layout(storage)?;
+        let offset = layout.first_pokemon + layout.pokemon_size * (slot - 1);
+        let species_offset = layout.count_offset + slot;
+
+        self.transaction(|save| {
+            save.write_bytes(offset, data)?;
+            save.write_byte(species_offset, data[offsets::PARTY_SPECIES_ID])
+        })
+    }
+
+    // Runs every structural check in SaveValidator and returns what it found.
+    pub fn validate(&self) -> Result<Vec<Diagnostic>> {
+        SaveValidator::new(self).validate()
+    }
+
+    // Fixes what SaveRepairer can fix safely and recomputes all checksums. Returns every change made.
+    pub fn repair(&mut self) -> Result<Vec<RepairAction>> {
+        self.transaction(|save| SaveRepairer::new(save).repair())
+    }
+
+    pub fn get_current_box(&self) -> Result<usize> {
+        Ok(((self.read_byte(offsets::CURRENT_BOX)? & 0x7F) + 1) as usize)
     }
     
     
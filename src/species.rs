@@ -0,0 +1,371 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::stats::BaseStats;
+use crate::types::{PokemonType, NORMAL, FIGHTING, FLYING, POISON, GROUND, ROCK, BIRD, BUG, GHOST, FIRE, WATER, GRASS, ELECTRIC, PSYCHIC, ICE, DRAGON};
+
+use GrowthRate::*;
+
+// How much experience a species needs to reach each level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GrowthRate {
+    MediumFast,
+    MediumSlow,
+    Fast,
+    Slow,
+}
+
+// Everything the game knows about a species, keyed by its internal index.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SpeciesInfo {
+    pub index: u8,
+    // National Pokedex number. 0 for the MissingNo indices.
+    pub dex_number: u8,
+    pub name: &'static str,
+    pub base_stats: BaseStats,
+    pub type_1: PokemonType,
+    pub type_2: PokemonType,
+    pub catch_rate: u8,
+    pub base_exp: u8,
+    pub growth_rate: GrowthRate,
+    // Moves known at level 1, 0 for an empty slot.
+    pub starting_moves: [u8; 4],
+}
+
+impl SpeciesInfo {
+    pub fn is_missingno(&self) -> bool {
+        self.dex_number == 0
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn species(
+    index: u8,
+    dex_number: u8,
+    name: &'static str,
+    base: [u8; 5],
+    types: [PokemonType; 2],
+    catch_rate: u8,
+    base_exp: u8,
+    growth_rate: GrowthRate,
+    starting_moves: [u8; 4],
+) -> SpeciesInfo {
+    SpeciesInfo {
+        index,
+        dex_number,
+        name,
+        base_stats: BaseStats { hp: base[0], attack: base[1], defense: base[2], speed: base[3], special: base[4] },
+        type_1: types[0],
+        type_2: types[1],
+        catch_rate,
+        base_exp,
+        growth_rate,
+        starting_moves,
+    }
+}
+
+// Unused indices all have Pokedex number 0, so the game reads the same glitched
+// header for every one of them. This is that header.
+const fn missingno(index: u8) -> SpeciesInfo {
+    species(index, 0, "MissingNo", [33, 136, 0, 29, 6], [BIRD, NORMAL], 29, 143, MediumSlow, [55, 55, 143, 0])
+}
+
+// Every internal index from 1 to 190, in index order.
+static SPECIES: [SpeciesInfo; 190] = [
+    species(  1, 112, "Rhydon", [105, 130, 120,  40,  45], [GROUND, ROCK], 60, 204, Slow, [30, 23, 39, 31]),
+    species(  2, 115, "Kangaskhan", [105,  95,  80,  90,  40], [NORMAL, NORMAL], 45, 175, MediumFast, [4, 99, 0, 0]),
+    species(  3,  32, "Nidoran♂", [ 46,  57,  40,  50,  40], [POISON, POISON], 235, 60, MediumSlow, [43, 33, 0, 0]),
+    species(  4,  35, "Clefairy", [ 70,  45,  48,  35,  60], [NORMAL, NORMAL], 150, 68, Fast, [1, 45, 0, 0]),
+    species(  5,  21, "Spearow", [ 40,  60,  30,  70,  31], [NORMAL, FLYING], 255, 58, MediumFast, [64, 45, 0, 0]),
+    species(  6, 100, "Voltorb", [ 40,  30,  50, 100,  55], [ELECTRIC, ELECTRIC], 190, 103, MediumFast, [33, 103, 0, 0]),
+    species(  7,  34, "Nidoking", [ 81,  92,  77,  85,  75], [POISON, GROUND], 45, 195, MediumSlow, [33, 30, 40, 37]),
+    species(  8,  80, "Slowbro", [ 95,  75, 110,  30,  80], [WATER, PSYCHIC], 75, 164, MediumFast, [93, 50, 29, 0]),
+    species(  9,   2, "Ivysaur", [ 60,  62,  63,  60,  80], [GRASS, POISON], 45, 141, MediumSlow, [33, 45, 73, 0]),
+    species( 10, 103, "Exeggutor", [ 95,  95,  85,  55, 125], [GRASS, PSYCHIC], 45, 212, Slow, [140, 95, 0, 0]),
+    species( 11, 108, "Lickitung", [ 90,  55,  75,  30,  60], [NORMAL, NORMAL], 45, 127, MediumFast, [35, 48, 0, 0]),
+    species( 12, 102, "Exeggcute", [ 60,  40,  80,  40,  60], [GRASS, PSYCHIC], 90, 98, Slow, [140, 95, 0, 0]),
+    species( 13,  88, "Grimer", [ 80,  80,  50,  25,  40], [POISON, POISON], 190, 90, MediumFast, [1, 50, 0, 0]),
+    species( 14,  94, "Gengar", [ 60,  65,  60, 110, 130], [GHOST, POISON], 45, 190, MediumSlow, [122, 109, 101, 0]),
+    species( 15,  29, "Nidoran♀", [ 55,  47,  52,  41,  40], [POISON, POISON], 235, 59, MediumSlow, [45, 33, 0, 0]),
+    species( 16,  31, "Nidoqueen", [ 90,  82,  87,  76,  75], [POISON, GROUND], 45, 194, MediumSlow, [33, 10, 39, 34]),
+    species( 17, 104, "Cubone", [ 50,  50,  95,  35,  40], [GROUND, GROUND], 190, 87, MediumFast, [125, 45, 0, 0]),
+    species( 18, 111, "Rhyhorn", [ 80,  85,  95,  25,  30], [GROUND, ROCK], 120, 135, Slow, [30, 0, 0, 0]),
+    species( 19, 131, "Lapras", [130,  85,  80,  60,  95], [WATER, ICE], 45, 219, Slow, [55, 45, 0, 0]),
+    species( 20,  59, "Arcanine", [ 90, 110,  80,  95,  80], [FIRE, FIRE], 75, 213, Slow, [46, 52, 43, 36]),
+    species( 21, 151, "Mew", [100, 100, 100, 100, 100], [PSYCHIC, PSYCHIC], 45, 64, MediumSlow, [1, 0, 0, 0]),
+    species( 22, 130, "Gyarados", [ 95, 125,  79,  81, 100], [WATER, FLYING], 45, 214, Slow, [44, 82, 43, 56]),
+    species( 23,  90, "Shellder", [ 30,  65, 100,  40,  45], [WATER, WATER], 190, 97, Slow, [33, 110, 0, 0]),
+    species( 24,  72, "Tentacool", [ 40,  40,  35,  70, 100], [WATER, POISON], 190, 105, Slow, [51, 0, 0, 0]),
+    species( 25,  92, "Gastly", [ 30,  35,  30,  80, 100], [GHOST, POISON], 190, 95, MediumSlow, [122, 109, 101, 0]),
+    species( 26, 123, "Scyther", [ 70, 110,  80, 105,  55], [BUG, FLYING], 45, 187, MediumFast, [98, 0, 0, 0]),
+    species( 27, 120, "Staryu", [ 30,  45,  55,  85,  70], [WATER, WATER], 225, 106, Slow, [33, 0, 0, 0]),
+    species( 28,   9, "Blastoise", [ 79,  83, 100,  78,  85], [WATER, WATER], 45, 210, MediumSlow, [33, 39, 145, 55]),
+    species( 29, 127, "Pinsir", [ 65, 125, 100,  85,  55], [BUG, BUG], 45, 200, Slow, [11, 0, 0, 0]),
+    species( 30, 114, "Tangela", [ 65,  55, 115,  60, 100], [GRASS, GRASS], 45, 166, MediumFast, [132, 20, 0, 0]),
+    missingno(31),
+    missingno(32),
+    species( 33,  58, "Growlithe", [ 55,  70,  45,  60,  50], [FIRE, FIRE], 190, 91, Slow, [44, 46, 0, 0]),
+    species( 34,  95, "Onix", [ 35,  45, 160,  70,  30], [ROCK, GROUND], 45, 108, MediumFast, [33, 103, 0, 0]),
+    species( 35,  22, "Fearow", [ 65,  90,  65, 100,  61], [NORMAL, FLYING], 90, 162, MediumFast, [64, 45, 43, 0]),
+    species( 36,  16, "Pidgey", [ 40,  45,  40,  56,  35], [NORMAL, FLYING], 255, 55, MediumSlow, [16, 0, 0, 0]),
+    species( 37,  79, "Slowpoke", [ 90,  65,  65,  15,  40], [WATER, PSYCHIC], 190, 99, MediumFast, [93, 0, 0, 0]),
+    species( 38,  64, "Kadabra", [ 40,  35,  30, 105, 120], [PSYCHIC, PSYCHIC], 100, 145, MediumSlow, [100, 93, 50, 0]),
+    species( 39,  75, "Graveler", [ 55,  95, 115,  35,  45], [ROCK, GROUND], 120, 134, MediumSlow, [33, 111, 0, 0]),
+    species( 40, 113, "Chansey", [250,   5,   5,  50, 105], [NORMAL, NORMAL], 30, 255, Fast, [1, 3, 0, 0]),
+    species( 41,  67, "Machoke", [ 80, 100,  70,  45,  50], [FIGHTING, FIGHTING], 90, 146, MediumSlow, [2, 67, 43, 0]),
+    species( 42, 122, "Mr. Mime", [ 40,  45,  65,  90, 100], [PSYCHIC, PSYCHIC], 45, 136, MediumFast, [93, 112, 0, 0]),
+    species( 43, 106, "Hitmonlee", [ 50, 120,  53,  87,  35], [FIGHTING, FIGHTING], 45, 139, MediumFast, [24, 96, 0, 0]),
+    species( 44, 107, "Hitmonchan", [ 50, 105,  79,  76,  35], [FIGHTING, FIGHTING], 45, 140, MediumFast, [4, 97, 0, 0]),
+    species( 45,  24, "Arbok", [ 60,  85,  69,  80,  65], [POISON, POISON], 90, 147, MediumFast, [35, 43, 40, 0]),
+    species( 46,  47, "Parasect", [ 60,  95,  80,  30,  80], [BUG, GRASS], 75, 128, MediumFast, [10, 78, 141, 0]),
+    species( 47,  54, "Psyduck", [ 50,  52,  48,  55,  50], [WATER, WATER], 190, 80, MediumFast, [10, 0, 0, 0]),
+    species( 48,  96, "Drowzee", [ 60,  48,  45,  42,  90], [PSYCHIC, PSYCHIC], 190, 102, MediumFast, [1, 95, 0, 0]),
+    species( 49,  76, "Golem", [ 80, 110, 130,  45,  55], [ROCK, GROUND], 45, 177, MediumSlow, [33, 111, 0, 0]),
+    missingno(50),
+    species( 51, 126, "Magmar", [ 65,  95,  57,  93,  85], [FIRE, FIRE], 45, 167, MediumFast, [52, 0, 0, 0]),
+    missingno(52),
+    species( 53, 125, "Electabuzz", [ 65,  83,  57, 105,  85], [ELECTRIC, ELECTRIC], 45, 156, MediumFast, [98, 43, 0, 0]),
+    species( 54,  82, "Magneton", [ 50,  60,  95,  70, 120], [ELECTRIC, ELECTRIC], 60, 161, MediumFast, [33, 49, 84, 0]),
+    species( 55, 109, "Koffing", [ 40,  65,  95,  35,  60], [POISON, POISON], 190, 114, MediumFast, [33, 123, 0, 0]),
+    missingno(56),
+    species( 57,  56, "Mankey", [ 40,  80,  35,  70,  35], [FIGHTING, FIGHTING], 190, 74, MediumFast, [10, 43, 0, 0]),
+    species( 58,  86, "Seel", [ 65,  45,  55,  45,  70], [WATER, WATER], 190, 100, MediumFast, [29, 0, 0, 0]),
+    species( 59,  50, "Diglett", [ 10,  55,  25,  95,  45], [GROUND, GROUND], 255, 81, MediumFast, [10, 0, 0, 0]),
+    species( 60, 128, "Tauros", [ 75, 100,  95, 110,  70], [NORMAL, NORMAL], 45, 211, Slow, [33, 0, 0, 0]),
+    missingno(61),
+    missingno(62),
+    missingno(63),
+    species( 64,  83, "Farfetch'd", [ 52,  65,  55,  60,  58], [NORMAL, FLYING], 45, 94, MediumFast, [64, 28, 0, 0]),
+    species( 65,  48, "Venonat", [ 60,  55,  50,  45,  40], [BUG, POISON], 190, 75, MediumFast, [33, 50, 0, 0]),
+    species( 66, 149, "Dragonite", [ 91, 134,  95,  80, 100], [DRAGON, FLYING], 45, 218, Slow, [35, 43, 86, 97]),
+    missingno(67),
+    missingno(68),
+    missingno(69),
+    species( 70,  84, "Doduo", [ 35,  85,  45,  75,  35], [NORMAL, FLYING], 190, 96, MediumFast, [64, 0, 0, 0]),
+    species( 71,  60, "Poliwag", [ 40,  50,  40,  90,  40], [WATER, WATER], 255, 77, MediumSlow, [145, 0, 0, 0]),
+    species( 72, 124, "Jynx", [ 65,  50,  35,  95,  95], [ICE, PSYCHIC], 45, 137, MediumFast, [1, 142, 0, 0]),
+    species( 73, 146, "Moltres", [ 90, 100,  90,  90, 125], [FIRE, FLYING], 3, 217, Slow, [64, 83, 0, 0]),
+    species( 74, 144, "Articuno", [ 90,  85, 100,  85, 125], [ICE, FLYING], 3, 215, Slow, [64, 58, 0, 0]),
+    species( 75, 145, "Zapdos", [ 90,  90,  85, 100, 125], [ELECTRIC, FLYING], 3, 216, Slow, [84, 65, 0, 0]),
+    species( 76, 132, "Ditto", [ 48,  48,  48,  48,  48], [NORMAL, NORMAL], 35, 61, MediumFast, [144, 0, 0, 0]),
+    species( 77,  52, "Meowth", [ 40,  45,  35,  90,  40], [NORMAL, NORMAL], 255, 69, MediumFast, [10, 45, 0, 0]),
+    species( 78,  98, "Krabby", [ 30, 105,  90,  50,  25], [WATER, WATER], 225, 115, MediumFast, [145, 43, 0, 0]),
+    missingno(79),
+    missingno(80),
+    missingno(81),
+    species( 82,  37, "Vulpix", [ 38,  41,  40,  65,  65], [FIRE, FIRE], 190, 63, MediumFast, [52, 39, 0, 0]),
+    species( 83,  38, "Ninetales", [ 73,  76,  75, 100, 100], [FIRE, FIRE], 75, 178, MediumFast, [52, 39, 98, 46]),
+    species( 84,  25, "Pikachu", [ 35,  55,  30,  90,  50], [ELECTRIC, ELECTRIC], 190, 82, MediumFast, [84, 45, 0, 0]),
+    species( 85,  26, "Raichu", [ 60,  90,  55, 100,  90], [ELECTRIC, ELECTRIC], 75, 122, MediumFast, [84, 45, 86, 0]),
+    missingno(86),
+    missingno(87),
+    species( 88, 147, "Dratini", [ 41,  64,  45,  50,  50], [DRAGON, DRAGON], 45, 67, Slow, [35, 43, 0, 0]),
+    species( 89, 148, "Dragonair", [ 61,  84,  65,  70,  70], [DRAGON, DRAGON], 45, 144, Slow, [35, 43, 86, 0]),
+    species( 90, 140, "Kabuto", [ 30,  80,  90,  55,  45], [ROCK, WATER], 45, 119, MediumFast, [10, 106, 0, 0]),
+    species( 91, 141, "Kabutops", [ 60, 115, 105,  80,  70], [ROCK, WATER], 45, 201, MediumFast, [10, 106, 71, 0]),
+    species( 92, 116, "Horsea", [ 30,  40,  70,  60,  70], [WATER, WATER], 225, 83, MediumFast, [145, 0, 0, 0]),
+    species( 93, 117, "Seadra", [ 55,  65,  95,  85,  95], [WATER, WATER], 75, 155, MediumFast, [145, 108, 0, 0]),
+    missingno(94),
+    missingno(95),
+    species( 96,  27, "Sandshrew", [ 50,  75,  85,  40,  30], [GROUND, GROUND], 255, 93, MediumFast, [10, 0, 0, 0]),
+    species( 97,  28, "Sandslash", [ 75, 100, 110,  65,  55], [GROUND, GROUND], 90, 163, MediumFast, [10, 28, 0, 0]),
+    species( 98, 138, "Omanyte", [ 35,  40, 100,  35,  90], [ROCK, WATER], 45, 120, MediumFast, [55, 110, 0, 0]),
+    species( 99, 139, "Omastar", [ 70,  60, 125,  55, 115], [ROCK, WATER], 45, 199, MediumFast, [55, 110, 30, 0]),
+    species(100,  39, "Jigglypuff", [115,  45,  20,  20,  25], [NORMAL, NORMAL], 170, 76, Fast, [47, 0, 0, 0]),
+    species(101,  40, "Wigglytuff", [140,  70,  45,  45,  50], [NORMAL, NORMAL], 50, 109, Fast, [47, 50, 111, 3]),
+    species(102, 133, "Eevee", [ 55,  55,  50,  55,  65], [NORMAL, NORMAL], 45, 92, MediumFast, [33, 28, 0, 0]),
+    species(103, 136, "Flareon", [ 65, 130,  60,  65, 110], [FIRE, FIRE], 45, 198, MediumFast, [33, 28, 98, 52]),
+    species(104, 135, "Jolteon", [ 65,  65,  60, 130, 110], [ELECTRIC, ELECTRIC], 45, 197, MediumFast, [33, 28, 98, 84]),
+    species(105, 134, "Vaporeon", [130,  65,  60,  65, 110], [WATER, WATER], 45, 196, MediumFast, [33, 28, 98, 55]),
+    species(106,  66, "Machop", [ 70,  80,  50,  35,  35], [FIGHTING, FIGHTING], 180, 88, MediumSlow, [2, 0, 0, 0]),
+    species(107,  41, "Zubat", [ 40,  45,  35,  55,  40], [POISON, FLYING], 255, 54, MediumFast, [141, 0, 0, 0]),
+    species(108,  23, "Ekans", [ 35,  60,  44,  55,  40], [POISON, POISON], 255, 62, MediumFast, [35, 43, 0, 0]),
+    species(109,  46, "Paras", [ 35,  70,  55,  25,  55], [BUG, GRASS], 190, 70, MediumFast, [10, 0, 0, 0]),
+    species(110,  61, "Poliwhirl", [ 65,  65,  65,  90,  50], [WATER, WATER], 120, 131, MediumSlow, [145, 95, 55, 0]),
+    species(111,  62, "Poliwrath", [ 90,  85,  95,  70,  70], [WATER, FIGHTING], 45, 185, MediumSlow, [95, 55, 3, 34]),
+    species(112,  13, "Weedle", [ 40,  35,  30,  50,  20], [BUG, POISON], 255, 52, MediumFast, [40, 81, 0, 0]),
+    species(113,  14, "Kakuna", [ 45,  25,  50,  35,  25], [BUG, POISON], 120, 71, MediumFast, [106, 0, 0, 0]),
+    species(114,  15, "Beedrill", [ 65,  80,  40,  75,  45], [BUG, POISON], 45, 159, MediumFast, [31, 0, 0, 0]),
+    missingno(115),
+    species(116,  85, "Dodrio", [ 60, 110,  70, 100,  60], [NORMAL, FLYING], 45, 158, MediumFast, [64, 45, 31, 0]),
+    species(117,  57, "Primeape", [ 65, 105,  60,  95,  60], [FIGHTING, FIGHTING], 75, 149, MediumFast, [10, 43, 2, 154]),
+    species(118,  51, "Dugtrio", [ 35,  80,  50, 120,  70], [GROUND, GROUND], 50, 153, MediumFast, [10, 45, 91, 0]),
+    species(119,  49, "Venomoth", [ 70,  65,  60,  90,  90], [BUG, POISON], 75, 138, MediumFast, [33, 50, 77, 141]),
+    species(120,  87, "Dewgong", [ 90,  70,  80,  70,  95], [WATER, ICE], 75, 176, MediumFast, [29, 45, 62, 0]),
+    missingno(121),
+    missingno(122),
+    species(123,  10, "Caterpie", [ 45,  30,  35,  45,  20], [BUG, BUG], 255, 53, MediumFast, [33, 81, 0, 0]),
+    species(124,  11, "Metapod", [ 50,  20,  55,  30,  25], [BUG, BUG], 120, 72, MediumFast, [106, 0, 0, 0]),
+    species(125,  12, "Butterfree", [ 60,  45,  50,  70,  80], [BUG, FLYING], 45, 160, MediumFast, [93, 0, 0, 0]),
+    species(126,  68, "Machamp", [ 90, 130,  80,  55,  65], [FIGHTING, FIGHTING], 45, 193, MediumSlow, [2, 67, 43, 0]),
+    missingno(127),
+    species(128,  55, "Golduck", [ 80,  82,  78,  85,  80], [WATER, WATER], 75, 174, MediumFast, [10, 39, 50, 0]),
+    species(129,  97, "Hypno", [ 85,  73,  70,  67, 115], [PSYCHIC, PSYCHIC], 75, 165, MediumFast, [1, 95, 50, 93]),
+    species(130,  42, "Golbat", [ 75,  80,  70,  90,  75], [POISON, FLYING], 90, 171, MediumFast, [141, 103, 44, 0]),
+    species(131, 150, "Mewtwo", [106, 110,  90, 130, 154], [PSYCHIC, PSYCHIC], 3, 220, Slow, [93, 50, 129, 94]),
+    species(132, 143, "Snorlax", [160, 110,  65,  30,  65], [NORMAL, NORMAL], 25, 154, Slow, [29, 133, 156, 0]),
+    species(133, 129, "Magikarp", [ 20,  10,  55,  80,  20], [WATER, WATER], 255, 20, Slow, [150, 0, 0, 0]),
+    missingno(134),
+    missingno(135),
+    species(136,  89, "Muk", [105, 105,  75,  50,  65], [POISON, POISON], 75, 157, MediumFast, [1, 50, 139, 0]),
+    missingno(137),
+    species(138,  99, "Kingler", [ 55, 130, 115,  75,  50], [WATER, WATER], 60, 206, MediumFast, [145, 43, 11, 0]),
+    species(139,  91, "Cloyster", [ 50,  95, 180,  70,  85], [WATER, ICE], 60, 203, Slow, [110, 48, 128, 62]),
+    missingno(140),
+    species(141, 101, "Electrode", [ 60,  50,  70, 140,  80], [ELECTRIC, ELECTRIC], 60, 150, MediumFast, [33, 103, 49, 0]),
+    species(142,  36, "Clefable", [ 95,  70,  73,  60,  85], [NORMAL, NORMAL], 25, 129, Fast, [47, 3, 107, 118]),
+    species(143, 110, "Weezing", [ 65,  90, 120,  60,  85], [POISON, POISON], 60, 173, MediumFast, [33, 123, 124, 0]),
+    species(144,  53, "Persian", [ 65,  70,  60, 115,  65], [NORMAL, NORMAL], 90, 148, MediumFast, [10, 45, 44, 103]),
+    species(145, 105, "Marowak", [ 60,  80, 110,  45,  50], [GROUND, GROUND], 75, 124, MediumFast, [125, 45, 43, 116]),
+    missingno(146),
+    species(147,  93, "Haunter", [ 45,  50,  45,  95, 115], [GHOST, POISON], 90, 126, MediumSlow, [122, 109, 101, 0]),
+    species(148,  63, "Abra", [ 25,  20,  15,  90, 105], [PSYCHIC, PSYCHIC], 200, 73, MediumSlow, [100, 0, 0, 0]),
+    species(149,  65, "Alakazam", [ 55,  50,  45, 120, 135], [PSYCHIC, PSYCHIC], 50, 186, MediumSlow, [100, 93, 50, 0]),
+    species(150,  17, "Pidgeotto", [ 63,  60,  55,  71,  50], [NORMAL, FLYING], 120, 113, MediumSlow, [16, 28, 0, 0]),
+    species(151,  18, "Pidgeot", [ 83,  80,  75,  91,  70], [NORMAL, FLYING], 45, 172, MediumSlow, [16, 28, 98, 0]),
+    species(152, 121, "Starmie", [ 60,  75,  85, 115, 100], [WATER, PSYCHIC], 60, 207, Slow, [33, 55, 106, 0]),
+    species(153,   1, "Bulbasaur", [ 45,  49,  49,  45,  65], [GRASS, POISON], 45, 64, MediumSlow, [33, 45, 0, 0]),
+    species(154,   3, "Venusaur", [ 80,  82,  83,  80, 100], [GRASS, POISON], 45, 208, MediumSlow, [33, 45, 73, 22]),
+    species(155,  73, "Tentacruel", [ 80,  70,  65, 100, 120], [WATER, POISON], 60, 205, Slow, [51, 48, 35, 0]),
+    missingno(156),
+    species(157, 118, "Goldeen", [ 45,  67,  60,  63,  50], [WATER, WATER], 225, 111, MediumFast, [64, 39, 0, 0]),
+    species(158, 119, "Seaking", [ 80,  92,  65,  68,  80], [WATER, WATER], 60, 170, MediumFast, [64, 39, 48, 0]),
+    missingno(159),
+    missingno(160),
+    missingno(161),
+    missingno(162),
+    species(163,  77, "Ponyta", [ 50,  85,  55,  90,  65], [FIRE, FIRE], 190, 152, MediumFast, [52, 0, 0, 0]),
+    species(164,  78, "Rapidash", [ 65, 100,  70, 105,  80], [FIRE, FIRE], 60, 192, MediumFast, [52, 39, 23, 45]),
+    species(165,  19, "Rattata", [ 30,  56,  35,  72,  25], [NORMAL, NORMAL], 255, 57, MediumFast, [33, 39, 0, 0]),
+    species(166,  20, "Raticate", [ 55,  81,  60,  97,  50], [NORMAL, NORMAL], 90, 116, MediumFast, [33, 39, 98, 0]),
+    species(167,  33, "Nidorino", [ 61,  72,  57,  65,  55], [POISON, POISON], 120, 118, MediumSlow, [43, 33, 30, 0]),
+    species(168,  30, "Nidorina", [ 70,  62,  67,  56,  55], [POISON, POISON], 120, 117, MediumSlow, [45, 33, 10, 0]),
+    species(169,  74, "Geodude", [ 40,  80, 100,  20,  30], [ROCK, GROUND], 255, 86, MediumSlow, [33, 0, 0, 0]),
+    species(170, 137, "Porygon", [ 65,  60,  70,  40,  75], [NORMAL, NORMAL], 45, 130, MediumFast, [33, 159, 160, 0]),
+    species(171, 142, "Aerodactyl", [ 80, 105,  65, 130,  60], [ROCK, FLYING], 45, 202, Slow, [17, 97, 0, 0]),
+    missingno(172),
+    species(173,  81, "Magnemite", [ 25,  35,  70,  45,  95], [ELECTRIC, ELECTRIC], 190, 89, MediumFast, [33, 0, 0, 0]),
+    missingno(174),
+    missingno(175),
+    species(176,   4, "Charmander", [ 39,  52,  43,  65,  50], [FIRE, FIRE], 45, 65, MediumSlow, [10, 45, 0, 0]),
+    species(177,   7, "Squirtle", [ 44,  48,  65,  43,  50], [WATER, WATER], 45, 66, MediumSlow, [33, 39, 0, 0]),
+    species(178,   5, "Charmeleon", [ 58,  64,  58,  80,  65], [FIRE, FIRE], 45, 142, MediumSlow, [10, 45, 52, 0]),
+    species(179,   8, "Wartortle", [ 59,  63,  80,  58,  65], [WATER, WATER], 45, 143, MediumSlow, [33, 39, 145, 0]),
+    species(180,   6, "Charizard", [ 78,  84,  78, 100,  85], [FIRE, FLYING], 45, 209, MediumSlow, [10, 45, 52, 43]),
+    missingno(181),
+    missingno(182),
+    missingno(183),
+    missingno(184),
+    species(185,  43, "Oddish", [ 45,  50,  55,  30,  75], [GRASS, POISON], 255, 78, MediumSlow, [71, 0, 0, 0]),
+    species(186,  44, "Gloom", [ 60,  65,  70,  40,  85], [GRASS, POISON], 120, 132, MediumSlow, [71, 77, 78, 0]),
+    species(187,  45, "Vileplume", [ 75,  80,  85,  50, 100], [GRASS, POISON], 45, 184, MediumSlow, [78, 79, 51, 80]),
+    species(188,  69, "Bellsprout", [ 50,  75,  35,  40,  70], [GRASS, POISON], 255, 84, MediumSlow, [22, 74, 0, 0]),
+    species(189,  70, "Weepinbell", [ 65,  90,  50,  55,  85], [GRASS, POISON], 120, 151, MediumSlow, [22, 74, 35, 0]),
+    species(190,  71, "Victreebel", [ 80, 105,  65,  70, 100], [GRASS, POISON], 45, 191, MediumSlow, [79, 78, 51, 75]),
+];
+
+pub fn all() -> &'static [SpeciesInfo] {
+    &SPECIES
+}
+
+// Looks up a species by the internal index stored in save data. MissingNo indices are included.
+pub fn by_index(index: u8) -> Option<&'static SpeciesInfo> {
+    SPECIES.get((index as usize).checked_sub(1)?)
+}
+
+pub fn by_dex_number(dex_number: u8) -> Option<&'static SpeciesInfo> {
+    if dex_number == 0 {
+        return None;
+    }
+    SPECIES.iter().find(|s| s.dex_number == dex_number)
+}
+
+// Case-insensitive lookup by name. Spaces and punctuation are ignored and the gender signs
+// can be written as F/M, so "Mr Mime", "farfetchd" and "Nidoran F" all match. MissingNo isn't
+// a single species, so it is never matched.
+pub fn by_name(name: &str) -> Option<&'static SpeciesInfo> {
+    let name = normalize_name(name);
+    SPECIES.iter().find(|s| !s.is_missingno() && normalize_name(s.name) == name)
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '♀' => 'f',
+            '♂' => 'm',
+            c => c.to_ascii_lowercase(),
+        })
+        .filter(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+// A real species, stored as its internal index. MissingNo is not a Species.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Species(u8);
+
+impl Species {
+
+    pub fn from_index(index: u8) -> Option<Species> {
+        by_index(index).filter(|s| !s.is_missingno()).map(|s| Species(s.index))
+    }
+
+    pub fn from_dex_number(dex_number: u8) -> Option<Species> {
+        by_dex_number(dex_number).map(|s| Species(s.index))
+    }
+
+    // The internal index stored in save data.
+    pub fn index(self) -> u8 {
+        self.0
+    }
+
+    pub fn dex_number(self) -> u8 {
+        self.info().dex_number
+    }
+
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn info(self) -> &'static SpeciesInfo {
+        &SPECIES[self.0 as usize - 1]
+    }
+}
+
+impl From<Species> for u8 {
+    fn from(species: Species) -> u8 {
+        species.index()
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSpeciesError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseSpeciesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown species \"{}\". Use a name or a Pokedex number from 1 to 151", self.input)
+    }
+}
+
+impl std::error::Error for ParseSpeciesError {}
+
+// Accepts a species name (see by_name()) or a national Pokedex number such as "25" or "#025".
+impl FromStr for Species {
+    type Err = ParseSpeciesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let by_number = trimmed.strip_prefix('#').unwrap_or(trimmed).parse::<u8>().ok();
+
+        by_number.map_or_else(|| by_name(trimmed), by_dex_number)
+            .map(|info| Species(info.index))
+            .ok_or_else(|| ParseSpeciesError { input: s.to_string() })
+    }
+}
@@ -0,0 +1,76 @@
+use crate::pokemon::Pokemon;
+use crate::species;
+
+// Base stats of a species. Gen 1 has a single Special stat for both attack and defense.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BaseStats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+// The five stats stored in a party pokemon's data structure.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub max_hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub special: u16,
+}
+
+// Highest value the game's square root of stat experience can reach.
+const MAX_STAT_EXP_ROOT: u32 = 255;
+
+// The HP DV isn't stored. It is made of the lowest bit of each of the other four DVs.
+pub fn hp_dv(attack_dv: u8, defense_dv: u8, speed_dv: u8, special_dv: u8) -> u8 {
+    ((attack_dv & 1) << 3) | ((defense_dv & 1) << 2) | ((speed_dv & 1) << 1) | (special_dv & 1)
+}
+
+// The game takes the smallest whole number whose square is at least the stat experience
+// (a rounded up square root), capped at 255, and adds a quarter of it to the stat.
+pub fn stat_exp_bonus(stat_exp: u16) -> u32 {
+    let stat_exp = stat_exp as u32;
+    let mut root = 0;
+    while root * root < stat_exp && root < MAX_STAT_EXP_ROOT {
+        root += 1;
+    }
+    root / 4
+}
+
+fn core_stat(base: u8, dv: u8, stat_exp: u16, level: u8) -> u32 {
+    ((base as u32 + dv as u32) * 2 + stat_exp_bonus(stat_exp)) * level as u32 / 100
+}
+
+pub fn calc_hp(base: u8, dv: u8, stat_exp: u16, level: u8) -> u16 {
+    (core_stat(base, dv, stat_exp, level) + level as u32 + 10) as u16
+}
+
+pub fn calc_stat(base: u8, dv: u8, stat_exp: u16, level: u8) -> u16 {
+    (core_stat(base, dv, stat_exp, level) + 5) as u16
+}
+
+// Stats a pokemon should have for its species, DVs, stat experience and level.
+// Returns None if the species is unknown.
+pub fn calc_stats(pokemon: &Pokemon) -> Option<Stats> {
+    let base = base_stats(pokemon.species_id)?;
+    let level = pokemon.level;
+    let hp_dv = pokemon.hp_dv();
+
+    Some(Stats {
+        max_hp: calc_hp(base.hp, hp_dv, pokemon.hp_stat_exp, level),
+        attack: calc_stat(base.attack, pokemon.attack_iv, pokemon.attack_stat_exp, level),
+        defense: calc_stat(base.defense, pokemon.defense_iv, pokemon.defense_stat_exp, level),
+        speed: calc_stat(base.speed, pokemon.speed_iv, pokemon.speed_stat_exp, level),
+        special: calc_stat(base.special, pokemon.special_iv, pokemon.special_stat_exp, level),
+    })
+}
+
+// Base stats by internal species index. None for unknown species and MissingNo.
+pub fn base_stats(species_id: u8) -> Option<BaseStats> {
+    species::by_index(species_id)
+        .filter(|s| !s.is_missingno())
+        .map(|s| s.base_stats)
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use PokemonType::*;
+
+// Pokemon and move types, using the ids stored in save data. Ids 9-19 are unused.
+// Bird (6) is a leftover type only MissingNo has; the type chart treats it as neutral to everything.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PokemonType {
+    Normal = 0,
+    Fighting = 1,
+    Flying = 2,
+    Poison = 3,
+    Ground = 4,
+    Rock = 5,
+    Bird = 6,
+    Bug = 7,
+    Ghost = 8,
+    Fire = 20,
+    Water = 21,
+    Grass = 22,
+    Electric = 23,
+    Psychic = 24,
+    Ice = 25,
+    Dragon = 26,
+}
+
+// Short names for the species and move tables.
+pub(crate) const NORMAL: PokemonType = PokemonType::Normal;
+pub(crate) const FIGHTING: PokemonType = PokemonType::Fighting;
+pub(crate) const FLYING: PokemonType = PokemonType::Flying;
+pub(crate) const POISON: PokemonType = PokemonType::Poison;
+pub(crate) const GROUND: PokemonType = PokemonType::Ground;
+pub(crate) const ROCK: PokemonType = PokemonType::Rock;
+pub(crate) const BIRD: PokemonType = PokemonType::Bird;
+pub(crate) const BUG: PokemonType = PokemonType::Bug;
+pub(crate) const GHOST: PokemonType = PokemonType::Ghost;
+pub(crate) const FIRE: PokemonType = PokemonType::Fire;
+pub(crate) const WATER: PokemonType = PokemonType::Water;
+pub(crate) const GRASS: PokemonType = PokemonType::Grass;
+pub(crate) const ELECTRIC: PokemonType = PokemonType::Electric;
+pub(crate) const PSYCHIC: PokemonType = PokemonType::Psychic;
+pub(crate) const ICE: PokemonType = PokemonType::Ice;
+pub(crate) const DRAGON: PokemonType = PokemonType::Dragon;
+
+impl PokemonType {
+
+    pub const ALL: [PokemonType; 16] = [
+        Normal, Fighting, Flying, Poison, Ground, Rock, Bird, Bug, Ghost,
+        Fire, Water, Grass, Electric, Psychic, Ice, Dragon,
+    ];
+
+    pub fn from_id(id: u8) -> Option<PokemonType> {
+        PokemonType::ALL.into_iter().find(|t| t.id() == id)
+    }
+
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Normal => "Normal",
+            Fighting => "Fighting",
+            Flying => "Flying",
+            Poison => "Poison",
+            Ground => "Ground",
+            Rock => "Rock",
+            Bird => "Bird",
+            Bug => "Bug",
+            Ghost => "Ghost",
+            Fire => "Fire",
+            Water => "Water",
+            Grass => "Grass",
+            Electric => "Electric",
+            Psychic => "Psychic",
+            Ice => "Ice",
+            Dragon => "Dragon",
+        }
+    }
+}
+
+impl From<PokemonType> for u8 {
+    fn from(pokemon_type: PokemonType) -> u8 {
+        pokemon_type.id()
+    }
+}
+
+impl fmt::Display for PokemonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Effectiveness {
+    NoEffect,
+    NotVeryEffective,
+    Normal,
+    SuperEffective,
+}
+
+impl Effectiveness {
+    pub fn multiplier(self) -> f32 {
+        match self {
+            Effectiveness::NoEffect => 0.0,
+            Effectiveness::NotVeryEffective => 0.5,
+            Effectiveness::Normal => 1.0,
+            Effectiveness::SuperEffective => 2.0,
+        }
+    }
+}
+
+use Effectiveness::{NoEffect, NotVeryEffective, SuperEffective};
+
+// Every matchup that isn't neutral, as (attacking, defending, effectiveness). Unlike later
+// games, Ghost moves don't affect Psychic, Bug and Poison are super effective against each
+// other, and Ice is neutral against Fire.
+static TYPE_CHART: [(PokemonType, PokemonType, Effectiveness); 82] = [
+    (Water, Fire, SuperEffective), (Fire, Grass, SuperEffective), (Fire, Ice, SuperEffective),
+    (Grass, Water, SuperEffective), (Electric, Water, SuperEffective), (Water, Rock, SuperEffective),
+    (Ghost, Ghost, SuperEffective), (Fire, Bug, SuperEffective), (Water, Ground, SuperEffective),
+    (Electric, Flying, SuperEffective), (Grass, Ground, SuperEffective), (Grass, Rock, SuperEffective),
+    (Ice, Grass, SuperEffective), (Ice, Ground, SuperEffective), (Ice, Flying, SuperEffective),
+    (Fighting, Normal, SuperEffective), (Fighting, Rock, SuperEffective), (Fighting, Ice, SuperEffective),
+    (Poison, Grass, SuperEffective), (Poison, Bug, SuperEffective), (Ground, Fire, SuperEffective),
+    (Ground, Electric, SuperEffective), (Ground, Rock, SuperEffective), (Ground, Poison, SuperEffective),
+    (Flying, Fighting, SuperEffective), (Flying, Bug, SuperEffective), (Flying, Grass, SuperEffective),
+    (Psychic, Fighting, SuperEffective), (Psychic, Poison, SuperEffective), (Bug, Grass, SuperEffective),
+    (Bug, Psychic, SuperEffective), (Bug, Poison, SuperEffective), (Rock, Fire, SuperEffective),
+    (Rock, Flying, SuperEffective), (Rock, Bug, SuperEffective), (Rock, Ice, SuperEffective),
+    (Ice, Dragon, SuperEffective), (Dragon, Dragon, SuperEffective),
+    (Water, Water, NotVeryEffective), (Fire, Fire, NotVeryEffective), (Electric, Electric, NotVeryEffective),
+    (Ice, Ice, NotVeryEffective), (Grass, Grass, NotVeryEffective), (Psychic, Psychic, NotVeryEffective),
+    (Fire, Water, NotVeryEffective), (Grass, Fire, NotVeryEffective), (Water, Grass, NotVeryEffective),
+    (Electric, Grass, NotVeryEffective), (Normal, Rock, NotVeryEffective), (Fire, Rock, NotVeryEffective),
+    (Grass, Bug, NotVeryEffective), (Grass, Poison, NotVeryEffective), (Grass, Flying, NotVeryEffective),
+    (Ice, Water, NotVeryEffective), (Fighting, Poison, NotVeryEffective),
+    (Fighting, Flying, NotVeryEffective), (Fighting, Psychic, NotVeryEffective),
+    (Fighting, Bug, NotVeryEffective), (Poison, Poison, NotVeryEffective), (Poison, Ground, NotVeryEffective),
+    (Poison, Rock, NotVeryEffective), (Poison, Ghost, NotVeryEffective), (Ground, Grass, NotVeryEffective),
+    (Ground, Bug, NotVeryEffective), (Flying, Electric, NotVeryEffective), (Flying, Rock, NotVeryEffective),
+    (Bug, Fire, NotVeryEffective), (Bug, Fighting, NotVeryEffective), (Bug, Flying, NotVeryEffective),
+    (Bug, Ghost, NotVeryEffective), (Rock, Fighting, NotVeryEffective), (Rock, Ground, NotVeryEffective),
+    (Fire, Dragon, NotVeryEffective), (Water, Dragon, NotVeryEffective), (Electric, Dragon, NotVeryEffective),
+    (Grass, Dragon, NotVeryEffective),
+    (Ground, Flying, NoEffect), (Normal, Ghost, NoEffect), (Electric, Ground, NoEffect),
+    (Fighting, Ghost, NoEffect), (Ghost, Normal, NoEffect), (Ghost, Psychic, NoEffect),
+];
+
+pub fn effectiveness(attacking: PokemonType, defending: PokemonType) -> Effectiveness {
+    TYPE_CHART.iter()
+        .find(|(a, d, _)| *a == attacking && *d == defending)
+        .map_or(Effectiveness::Normal, |(_, _, e)| *e)
+}
+
+// Damage multiplier of an `attacking` move against a pokemon with these types. Single typed
+// pokemon have the same type twice, which only counts once.
+pub fn damage_multiplier(attacking: PokemonType, type_1: PokemonType, type_2: PokemonType) -> f32 {
+    let multiplier = effectiveness(attacking, type_1).multiplier();
+    if type_1 == type_2 {
+        return multiplier;
+    }
+    multiplier * effectiveness(attacking, type_2).multiplier()
+}
+
+// Every attacking type with its multiplier against these types, strongest first.
+// Neutral matchups are left out.
+pub fn matchups(type_1: PokemonType, type_2: PokemonType) -> Vec<(PokemonType, f32)> {
+    let mut matchups: Vec<(PokemonType, f32)> = PokemonType::ALL.into_iter()
+        .map(|t| (t, damage_multiplier(t, type_1, type_2)))
+        .filter(|(_, multiplier)| *multiplier != 1.0)
+        .collect();
+    matchups.sort_by(|a, b| b.1.total_cmp(&a.1));
+    matchups
+}
+
+// Attacking types that do more than normal damage against these types.
+pub fn weaknesses(type_1: PokemonType, type_2: PokemonType) -> Vec<(PokemonType, f32)> {
+    matchups(type_1, type_2).into_iter().filter(|(_, multiplier)| *multiplier > 1.0).collect()
+}
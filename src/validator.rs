@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::error::Result;
+use crate::items;
+use crate::offsets;
+use crate::pokemon::Pokemon;
+use crate::save_file::{ItemStorage, PokemonLocation, PokemonStorage, SaveFile};
+
+// A name stored in the save. Names are fixed 11 byte fields that must contain a 0x50 terminator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NameField {
+    Player,
+    Rival,
+    OtName(PokemonLocation),
+    Nickname(PokemonLocation),
+}
+
+// A single structural problem found in a save file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    // The count byte of a party or box is above its capacity.
+    PokemonCountOutOfRange { storage: PokemonStorage, count: u8, max: usize },
+    // No 0xFF terminator within the species list.
+    SpeciesListUnterminated { storage: PokemonStorage },
+    // The count byte disagrees with the number of entries before the species list terminator.
+    SpeciesListLengthMismatch { storage: PokemonStorage, count: u8, list_len: usize },
+    // The species list entry for a slot is not the species stored in the pokemon's data.
+    SpeciesListEntryMismatch { location: PokemonLocation, list_species: u8, struct_species: u8 },
+    UnknownSpecies { location: PokemonLocation, species_id: u8 },
+    UnknownMove { location: PokemonLocation, move_slot: usize, move_id: u8 },
+    ItemCountOutOfRange { storage: ItemStorage, count: u8, max: usize },
+    ItemListUnterminated { storage: ItemStorage, count: u8 },
+    UnknownItem { storage: ItemStorage, slot: usize, item_id: u8 },
+    UnterminatedName { field: NameField, offset: usize },
+    // A money byte with a nibble above 9, which is not valid BCD.
+    InvalidMoneyDigits { offset: usize, byte: u8 },
+}
+
+// Walks a save file and reports every structural inconsistency it finds without modifying anything.
+pub struct SaveValidator<'a> {
+    save: &'a SaveFile,
+}
+
+impl<'a> SaveValidator<'a> {
+
+    pub fn new(save: &'a SaveFile) -> Self {
+        SaveValidator { save }
+    }
+
+    pub fn validate(&self) -> Result<Vec<Diagnostic>> {
+        let mut diagnostics = Vec::new();
+
+        self.check_name(NameField::Player, offsets::PLAYER_NAME_OFFSET, &mut diagnostics)?;
+        self.check_name(NameField::Rival, offsets::RIVAL_NAME_OFFSET, &mut diagnostics)?;
+        self.check_money(&mut diagnostics)?;
+        self.check_items(ItemStorage::Bag, &mut diagnostics)?;
+        self.check_items(ItemStorage::PcBox, &mut diagnostics)?;
+
+        self.check_pokemon_list(PokemonStorage::Party, &mut diagnostics)?;
+        for box_number in 1..=offsets::NUM_POKEMON_BOXES {
+            self.check_pokemon_list(PokemonStorage::PcBox(box_number), &mut diagnostics)?;
+        }
+
+        Ok(diagnostics)
+    }
+
+    fn check_name(&self, field: NameField, offset: usize, diagnostics: &mut Vec<Diagnostic>) -> Result<()> {
+        let name = self.save.read_bytes(offset, offsets::PARTY_OT_NICK_SIZE)?;
+
+        if !name.contains(&offsets::NAME_TERMINATOR) {
+            diagnostics.push(Diagnostic::UnterminatedName { field, offset });
+        }
+        Ok(())
+    }
+
+    fn check_money(&self, diagnostics: &mut Vec<Diagnostic>) -> Result<()> {
+        for offset in offsets::MONEY_OFFSET..offsets::MONEY_OFFSET + 3 {
+            let byte = self.save.read_byte(offset)?;
+            if (byte >> 4) > 9 || (byte & 0x0F) > 9 {
+                diagnostics.push(Diagnostic::InvalidMoneyDigits { offset, byte });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_items(&self, storage: ItemStorage, diagnostics: &mut Vec<Diagnostic>) -> Result<()> {
+        let (list_offset, max) = match storage {
+            ItemStorage::Bag => (offsets::BAG_OFFSET, offsets::MAX_BAG_ITEMS),
+            ItemStorage::PcBox => (offsets::BOX_ITEMS_OFFSET, offsets::MAX_BOX_ITEMS),
+        };
+
+        let count = self.save.read_byte(list_offset)?;
+        if count as usize > max {
+            diagnostics.push(Diagnostic::ItemCountOutOfRange { storage, count, max });
+        }
+
+        let count_in_range = (count as usize).min(max);
+        let first_item = list_offset + offsets::ITEM_LIST_FIRST_ITEM;
+        for slot in 0..count_in_range {
+            let item_id = self.save.read_byte(first_item + offsets::LIST_ITEM_SIZE * slot)?;
+            if !items::_is_valid_item(item_id) {
+                diagnostics.push(Diagnostic::UnknownItem { storage, slot: slot + 1, item_id });
+            }
+        }
+
+        let terminator_offset = first_item + offsets::LIST_ITEM_SIZE * count_in_range;
+        if self.save.read_byte(terminator_offset)? != offsets::ITEM_LIST_TERMINATOR {
+            diagnostics.push(Diagnostic::ItemListUnterminated { storage, count });
+        }
+        Ok(())
+    }
+
+    fn check_pokemon_list(&self, storage: PokemonStorage, diagnostics: &mut Vec<Diagnostic>) -> Result<()> {
+        let layout = self.save.pokemon_list_layout(storage)?;
+
+        let count = self.save.read_byte(layout.count_offset)?;
+        if count as usize > layout.capacity {
+            diagnostics.push(Diagnostic::PokemonCountOutOfRange { storage, count, max: layout.capacity });
+        }
+
+        // The species list holds one entry per slot plus the terminator.
+        let species_list = layout.count_offset + 1;
+        let list_len = self.save.read_bytes(species_list, layout.capacity + 1)?
+            .iter()
+            .position(|species| *species == offsets::PARTY_LIST_TERMINATOR);
+
+        match list_len {
+            None => diagnostics.push(Diagnostic::SpeciesListUnterminated { storage }),
+            Some(list_len) if list_len != count as usize => {
+                diagnostics.push(Diagnostic::SpeciesListLengthMismatch { storage, count, list_len });
+            }
+            Some(_) => (),
+        }
+
+        let count_in_range = (count as usize).min(layout.capacity);
+        for index in 0..count_in_range {
+            let location = PokemonLocation { storage, slot: index + 1 };
+            let struct_offset = layout.first_pokemon + layout.pokemon_size * index;
+
+            let struct_species = self.save.read_byte(struct_offset + offsets::PARTY_SPECIES_ID)?;
+            let list_species = self.save.read_byte(species_list + index)?;
+            if list_species != struct_species {
+                diagnostics.push(Diagnostic::SpeciesListEntryMismatch { location, list_species, struct_species });
+            }
+            if !Pokemon::is_valid_species(struct_species) {
+                diagnostics.push(Diagnostic::UnknownSpecies { location, species_id: struct_species });
+            }
+
+            for move_slot in 0..4 {
+                let move_id = self.save.read_byte(struct_offset + offsets::PARTY_MOVE_INDEX_1 + move_slot)?;
+                if !Pokemon::is_valid_move(move_id) {
+                    diagnostics.push(Diagnostic::UnknownMove { location, move_slot: move_slot + 1, move_id });
+                }
+            }
+
+            let ot_offset = layout.first_ot + offsets::PARTY_OT_NICK_SIZE * index;
+            let nick_offset = layout.first_nick + offsets::PARTY_OT_NICK_SIZE * index;
+            self.check_name(NameField::OtName(location), ot_offset, diagnostics)?;
+            self.check_name(NameField::Nickname(location), nick_offset, diagnostics)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NameField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameField::Player => write!(f, "Player name"),
+            NameField::Rival => write!(f, "Rival name"),
+            NameField::OtName(location) => write!(f, "OT name of {location}"),
+            NameField::Nickname(location) => write!(f, "Nickname of {location}"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::PokemonCountOutOfRange { storage, count, max } =>
+                write!(f, "{storage} count is {count}, maximum is {max}"),
+            Diagnostic::SpeciesListUnterminated { storage } =>
+                write!(f, "{storage} species list has no 0xFF terminator"),
+            Diagnostic::SpeciesListLengthMismatch { storage, count, list_len } =>
+                write!(f, "{storage} count is {count} but its species list has {list_len} entries"),
+            Diagnostic::SpeciesListEntryMismatch { location, list_species, struct_species } =>
+                write!(f, "{location} is listed as species {list_species} but its data holds species {struct_species}"),
+            Diagnostic::UnknownSpecies { location, species_id } =>
+                write!(f, "{location} has unknown species ID {species_id}"),
+            Diagnostic::UnknownMove { location, move_slot, move_id } =>
+                write!(f, "{location} has unknown move ID {move_id} in move slot {move_slot}"),
+            Diagnostic::ItemCountOutOfRange { storage, count, max } =>
+                write!(f, "{storage} item count is {count}, maximum is {max}"),
+            Diagnostic::ItemListUnterminated { storage, count } =>
+                write!(f, "{storage} item list with {count} items has no 0xFF terminator"),
+            Diagnostic::UnknownItem { storage, slot, item_id } =>
+                write!(f, "{storage} slot {slot} has unknown item ID 0x{item_id:02X}"),
+            Diagnostic::UnterminatedName { field, offset } =>
+                write!(f, "{field} at 0x{offset:04X} has no 0x50 terminator within {} bytes", offsets::PARTY_OT_NICK_SIZE),
+            Diagnostic::InvalidMoneyDigits { offset, byte } =>
+                write!(f, "Money byte 0x{byte:02X} at 0x{offset:04X} is not valid BCD"),
+        }
+    }
+}
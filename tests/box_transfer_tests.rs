@@ -0,0 +1,72 @@
+mod common;
+
+use chucksum::error::ChucksumError;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+fn box_order(save_file: &SaveFile, box_number: usize) -> Result<Vec<(u8, String, String, u32)>, ChucksumError> {
+    Ok(common::order(&save_file.get_stored_pokemon(PokemonStorage::PcBox(box_number))?))
+}
+
+#[test]
+fn pokemon_moves_across_banks() -> Result<(), ChucksumError> {
+    // Box 2 is the current box in pokemon blue.sav, box 8 is in bank 3 and box 1 in bank 2.
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let box_2 = box_order(&save_file, 2)?;
+    let box_8 = box_order(&save_file, 8)?;
+
+    save_file.move_box_pokemon(2, 4, 8)?;
+    assert_eq!(box_order(&save_file, 8)?.last(), Some(&box_2[3]));
+    assert_eq!(box_order(&save_file, 8)?.len(), box_8.len() + 1);
+    assert_eq!(box_order(&save_file, 2)?.len(), box_2.len() - 1);
+
+    let box_1 = box_order(&save_file, 1)?;
+    save_file.move_box_pokemon(8, box_8.len() + 1, 1)?;
+    assert_eq!(box_order(&save_file, 1)?.last(), Some(&box_2[3]));
+    assert_eq!(box_order(&save_file, 1)?.len(), box_1.len() + 1);
+    assert_eq!(box_order(&save_file, 8)?, box_8);
+    assert_eq!(save_file.validate()?, vec![]);
+
+    // Bank and box checksums are brought up to date on save
+    let reloaded = SaveFile::from_bytes(save_file.to_bytes()?)?;
+    assert!(reloaded.verify_checksums()?.is_valid());
+    assert_eq!(box_order(&reloaded, 1)?.last(), Some(&box_2[3]));
+    Ok(())
+}
+
+#[test]
+fn selections_and_whole_boxes_move_in_order() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let box_2 = box_order(&save_file, 2)?;
+    let box_9 = box_order(&save_file, 9)?;
+
+    save_file.move_box_pokemon_selection(2, &[5, 1, 3, 3], 9)?;
+    let mut expected_9 = box_9.clone();
+    expected_9.extend([box_2[0].clone(), box_2[2].clone(), box_2[4].clone()]);
+    assert_eq!(box_order(&save_file, 9)?, expected_9);
+
+    let remaining = box_order(&save_file, 2)?;
+    save_file.move_all_box_pokemon(2, 10)?;
+    assert!(box_order(&save_file, 2)?.is_empty());
+    assert!(box_order(&save_file, 10)?.ends_with(&remaining));
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn moves_that_dont_fit_change_nothing() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    for _ in 0..10 {
+        save_file.copy_party_pokemon(1, 11)?;
+    }
+    let before = save_file.to_bytes()?;
+
+    // Box 11 only has room for 10 of the 18 pokemon in box 2
+    assert_eq!(save_file.get_box_pokemon_count(11)?, 10);
+    let slots: Vec<usize> = (1..=11).collect();
+    assert!(matches!(save_file.move_box_pokemon_selection(2, &slots, 11), Err(ChucksumError::Pokemon(PokemonError::PokemonBoxFull))));
+    assert!(matches!(save_file.move_box_pokemon(2, 19, 11), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.move_box_pokemon(2, 1, 13), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert!(matches!(save_file.move_all_box_pokemon(0, 1), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
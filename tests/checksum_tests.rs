@@ -0,0 +1,56 @@
+use chucksum::checksum::{ChecksumRegion, LoadEffect};
+use chucksum::error::ChucksumError;
+use chucksum::save_file::SaveFile;
+
+#[test]
+fn bundled_saves_have_valid_checksums() -> Result<(), ChucksumError> {
+    for filename in ["data.srm", "pokemon blue.sav"] {
+        let save_file = SaveFile::new(filename)?;
+        let report = save_file.verify_checksums()?;
+
+        assert!(report.is_valid(), "{filename}:\n{report}");
+        assert!(report.loads_in_game());
+        assert_eq!(report.regions.len(), 15);
+    }
+    Ok(())
+}
+
+#[test]
+fn corrupted_box_is_reported_without_touching_main_block() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("data.srm")?;
+    let offset = ChecksumRegion::Box(3).start() + 0x30;
+    save_file.write_byte(offset, save_file.read_byte(offset)?.wrapping_add(1))?;
+
+    let report = save_file.verify_checksums()?;
+    let mismatched: Vec<ChecksumRegion> = report.mismatches().iter().map(|r| r.region).collect();
+
+    assert_eq!(mismatched, vec![ChecksumRegion::Bank2, ChecksumRegion::Box(3)]);
+    assert!(report.loads_in_game());
+    assert_eq!(report.region(ChecksumRegion::Box(3)).unwrap().load_effect(), LoadEffect::BoxesUnverified);
+    Ok(())
+}
+
+#[test]
+fn corrupted_main_block_destroys_file() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("data.srm")?;
+    let before = save_file.verify_checksums()?;
+    save_file.set_money(123_456)?;
+
+    let report = save_file.verify_checksums()?;
+    let main = report.region(ChecksumRegion::Main).unwrap();
+
+    assert!(!report.loads_in_game());
+    assert_eq!(main.stored, before.region(ChecksumRegion::Main).unwrap().stored);
+    assert_ne!(main.stored, main.computed);
+    assert_eq!(main.load_effect(), LoadEffect::FileDestroyed);
+    Ok(())
+}
+
+#[test]
+fn region_layout_matches_sram_banks() {
+    assert_eq!(ChecksumRegion::Box(1).start(), 0x4000);
+    assert_eq!(ChecksumRegion::Box(6).end(), 0x5A4B);
+    assert_eq!(ChecksumRegion::Box(7).start(), 0x6000);
+    assert_eq!(ChecksumRegion::Box(12).checksum_offset(), 0x7A52);
+    assert_eq!(ChecksumRegion::Bank3.end(), 0x7A4B);
+}
@@ -0,0 +1,6 @@
+use chucksum::save_file::StoredPokemon;
+
+// Species, nickname, OT and experience of each pokemon, so lists can be compared without their slots.
+pub fn order(pokemon: &[StoredPokemon]) -> Vec<(u8, String, String, u32)> {
+    pokemon.iter().map(|p| (p.pokemon.species_id, p.nickname.clone(), p.ot_name.clone(), p.pokemon.experience_pts)).collect()
+}
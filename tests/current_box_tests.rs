@@ -0,0 +1,53 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{PokemonStorage, SaveFile};
+
+#[test]
+fn current_box_is_read_from_working_copy() -> Result<(), ChucksumError> {
+    // Box 2 is selected in pokemon blue.sav, with 18 pokemon in its working copy.
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+
+    assert_eq!(save_file.get_current_box()?, 2);
+    assert_eq!(save_file.get_box_pokemon_count(2)?, 18);
+    assert_eq!(save_file.get_box_pokemon_count_offset(2)?, offsets::BOX_CURRENT_DATA_OFFSET);
+    assert_eq!(save_file.get_box_pokemon_count_offset(3)?, SaveFile::box_data_offset(3));
+    assert_eq!(save_file.get_box_pokemon_data(2)?, save_file.get_current_box_pokemon_data()?);
+    assert_eq!(save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?.len(), 18);
+    Ok(())
+}
+
+#[test]
+fn copy_into_current_box_updates_working_copy() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let bank_slot = save_file.read_bytes(SaveFile::box_data_offset(2), offsets::BOX_NEXT_BOX)?.to_vec();
+    let party = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+
+    save_file.copy_party_pokemon(1, 2)?;
+
+    let boxed = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?;
+    assert_eq!(boxed.len(), 19);
+    assert_eq!(boxed[18].pokemon.species_id, party[0].pokemon.species_id);
+    assert_eq!(boxed[18].nickname, party[0].nickname);
+    assert_eq!(boxed[18].ot_name, party[0].ot_name);
+
+    // The species list and terminator follow the working copy's count, and the stale bank slot is left alone
+    let list = offsets::BOX_CURRENT_DATA_OFFSET + 1;
+    assert_eq!(save_file.read_bytes(list + 18, 2)?, &[party[0].pokemon.species_id, offsets::PARTY_LIST_TERMINATOR]);
+    assert_eq!(save_file.read_bytes(SaveFile::box_data_offset(2), offsets::BOX_NEXT_BOX)?, bank_slot);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn full_current_box_is_refused() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.copy_party_pokemon(1, 2)?;
+    save_file.copy_party_pokemon(2, 2)?;
+
+    assert_eq!(save_file.get_box_pokemon_count(2)?, offsets::MAX_POKEMON_BOX_SIZE);
+    assert!(matches!(
+        save_file.copy_party_pokemon(3, 2),
+        Err(ChucksumError::Pokemon(chucksum::save_file::PokemonError::PokemonBoxFull))
+    ));
+    Ok(())
+}
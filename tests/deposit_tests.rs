@@ -0,0 +1,63 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn deposited_pokemon_leaves_the_party() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let party = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+
+    save_file.deposit_party_pokemon(1, 3)?;
+
+    let after = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+    assert_eq!(after.len(), party.len() - 1);
+    assert_eq!(after[0].nickname, party[1].nickname);
+
+    let boxed = save_file.get_stored_pokemon(PokemonStorage::PcBox(3))?;
+    let deposited = boxed.last().unwrap();
+    assert_eq!((deposited.nickname.as_str(), deposited.ot_name.as_str()), (party[0].nickname.as_str(), party[0].ot_name.as_str()));
+    assert_eq!(deposited.pokemon.species_id, party[0].pokemon.species_id);
+    assert_eq!(deposited.pokemon.box_level, party[0].pokemon.level);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn failed_deposit_changes_nothing() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.deposit_party_pokemon(4, 2)?;
+    save_file.deposit_party_pokemon(3, 2)?;
+    save_file.deposit_party_pokemon(2, 4)?;
+    assert_eq!(save_file.get_box_pokemon_count(2)?, 20);
+    assert_eq!(save_file.get_party_count()?, 1);
+
+    let before = save_file.to_bytes()?;
+    assert!(matches!(save_file.deposit_party_pokemon(1, 4), Err(ChucksumError::Pokemon(PokemonError::LastPartyPokemon))));
+    assert_eq!(save_file.to_bytes()?, before);
+
+    save_file.withdraw_box_pokemon(4, 1)?;
+    let before_full = save_file.to_bytes()?;
+    assert!(matches!(save_file.deposit_party_pokemon(1, 2), Err(ChucksumError::Pokemon(PokemonError::PokemonBoxFull))));
+    assert!(matches!(save_file.deposit_party_pokemon(3, 4), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    assert!(matches!(save_file.deposit_party_pokemon(1, 13), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert_eq!(save_file.to_bytes()?, before_full);
+    Ok(())
+}
+
+#[test]
+fn deposit_keeps_name_bytes_exactly() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    // "R2D2" with the game's digit characters (0xF6 is "0"), which the text encoding doesn't cover
+    let nickname = [0x91, 0xF8, 0x83, 0xF8, offsets::NAME_TERMINATOR, 0, 0, 0, 0, 0, 0];
+    save_file.write_bytes(offsets::PARTY_FIRST_NICK + offsets::PARTY_OT_NICK_SIZE, &nickname)?;
+    let ot_name = save_file.read_bytes(offsets::PARTY_FIRST_OT + offsets::PARTY_OT_NICK_SIZE, offsets::PARTY_OT_NICK_SIZE)?.to_vec();
+
+    save_file.deposit_party_pokemon(2, 3)?;
+
+    let layout = save_file.pokemon_list_layout(PokemonStorage::PcBox(3))?;
+    let slot = save_file.get_box_pokemon_count(3)? - 1;
+    let name_offset = offsets::PARTY_OT_NICK_SIZE * slot;
+    assert_eq!(save_file.read_bytes(layout.first_nick + name_offset, offsets::PARTY_OT_NICK_SIZE)?, nickname);
+    assert_eq!(save_file.read_bytes(layout.first_ot + name_offset, offsets::PARTY_OT_NICK_SIZE)?, ot_name);
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use chucksum::diff::{Change, FieldChange, SaveDiff};
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{ItemStorage, PokemonLocation, PokemonStorage, SaveFile};
+
+#[test]
+fn identical_saves_have_no_changes() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    assert!(SaveDiff::between(&save_file, &save_file)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn edited_copy_differs_from_source_data() -> Result<(), ChucksumError> {
+    let mut old = SaveFile::new("data.srm")?;
+    let mut new = SaveFile::from_bytes(old.to_bytes()?)?;
+
+    new.set_rival_name("GARY")?;
+    let mut added = Vec::new();
+    for box_number in [3, 9, 12] {
+        new.copy_party_pokemon(1, box_number)?;
+        added.push(PokemonLocation { storage: PokemonStorage::PcBox(box_number), slot: new.get_box_pokemon_count(box_number)? });
+    }
+    let new = SaveFile::from_bytes(new.to_bytes()?)?;
+    let diff = SaveDiff::between(&old, &new)?;
+
+    assert!(matches!(&diff.changes[0], Change::RivalName { from, to } if from == "ASSHAT" && to == "GARY"));
+    for location in added {
+        assert!(diff.changes.iter().any(|c| matches!(c, Change::PokemonAdded { location: l, .. } if *l == location)));
+    }
+    Ok(())
+}
+
+#[test]
+fn trainer_money_and_items_are_compared() -> Result<(), ChucksumError> {
+    let old = SaveFile::new("pokemon blue.sav")?;
+    let mut new = SaveFile::new("pokemon blue.sav")?;
+
+    let money = old.get_money()?;
+    new.set_money(money + 500)?;
+    new.add_item(ItemStorage::Bag, 0x01, 3)?;
+    let bag = old.get_items(ItemStorage::Bag)?;
+    let (first_id, first_qty) = bag[0];
+    new.write_byte(offsets::BAG_OFFSET + 2, first_qty + 1)?;
+
+    let diff = SaveDiff::between(&old, &new)?;
+    let money_change = Change::Money { from: money, to: money + 500 };
+
+    assert!(diff.changes.contains(&money_change));
+    assert_eq!(money_change.money_delta(), 500);
+    assert!(diff.changes.contains(&Change::ItemQuantity {
+        storage: ItemStorage::Bag,
+        item_id: first_id,
+        from: first_qty as u32,
+        to: first_qty as u32 + 1,
+    }));
+    assert!(diff.changes.iter().any(|c| matches!(c, Change::ItemAdded { storage: ItemStorage::Bag, item_id: 0x01, .. })
+        || matches!(c, Change::ItemQuantity { storage: ItemStorage::Bag, item_id: 0x01, .. })));
+    Ok(())
+}
+
+#[test]
+fn pokemon_field_edits_are_detected() -> Result<(), ChucksumError> {
+    let old = SaveFile::new("pokemon blue.sav")?;
+    let mut new = SaveFile::new("pokemon blue.sav")?;
+    let first = PokemonLocation { storage: PokemonStorage::Party, slot: 1 };
+
+    let level_offset = offsets::PARTY_FIRST_PKMN + offsets::PARTY_LEVEL;
+    let level = old.read_byte(level_offset)?;
+    new.write_byte(level_offset, level + 1)?;
+
+    let diff = SaveDiff::between(&old, &new)?;
+    let species_id = old.read_byte(offsets::PARTY_FIRST_PKMN)?;
+    assert_eq!(diff.changes, vec![Change::PokemonEdited {
+        location: first,
+        species_id,
+        changes: vec![FieldChange { field: "Level", from: level.to_string(), to: (level + 1).to_string() }],
+    }]);
+
+    // Copying the first party member into a box reads as a new pokemon, not a move
+    let mut copied = SaveFile::new("pokemon blue.sav")?;
+    copied.copy_party_pokemon(1, 1)?;
+    let diff = SaveDiff::between(&old, &copied)?;
+    assert!(diff.changes.iter().all(|c| matches!(c, Change::PokemonAdded { .. })), "{diff}");
+    Ok(())
+}
+
+#[test]
+fn deposited_pokemon_is_reported_as_moved() -> Result<(), ChucksumError> {
+    let old = SaveFile::new("pokemon blue.sav")?;
+    let mut new = SaveFile::new("pokemon blue.sav")?;
+
+    // Deposit the last of the four party members by copying it and shrinking the party
+    let box_count = old.get_box_pokemon_count(1)?;
+    new.copy_party_pokemon(4, 1)?;
+    new.write_byte(offsets::PARTY_DATA_OFFSET, 3)?;
+
+    let diff = SaveDiff::between(&old, &new)?;
+    assert_eq!(diff.changes.len(), 1, "{diff}");
+    assert!(matches!(&diff.changes[0], Change::PokemonMoved { from, to, .. }
+        if *from == PokemonLocation { storage: PokemonStorage::Party, slot: 4 }
+            && *to == PokemonLocation { storage: PokemonStorage::PcBox(1), slot: box_count + 1 }));
+    Ok(())
+}
+
+#[test]
+fn corrupt_box_is_reported_and_the_rest_still_compared() -> Result<(), ChucksumError> {
+    let old = SaveFile::new("pokemon blue.sav")?;
+    let mut new = SaveFile::new("pokemon blue.sav")?;
+
+    let money = old.get_money()?;
+    new.set_money(money + 1)?;
+    new.write_byte(new.get_box_pokemon_count_offset(5)?, 0xFF)?;
+    new.release_party_pokemon(2)?;
+
+    let diff = SaveDiff::between(&old, &new)?;
+    assert!(diff.changes.contains(&Change::Money { from: money, to: money + 1 }));
+    assert!(diff.changes.contains(&Change::PokemonListUnreadable { storage: PokemonStorage::PcBox(5) }));
+    assert!(diff.changes.iter().any(|c| matches!(c, Change::PokemonRemoved { .. })));
+    assert!(!diff.changes.iter().any(|c| matches!(c, Change::PokemonRemoved { location, .. } if location.storage == PokemonStorage::PcBox(5))));
+    Ok(())
+}
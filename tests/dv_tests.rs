@@ -0,0 +1,61 @@
+use chucksum::error::ChucksumError;
+use chucksum::pokemon::{DvChange, DvStat, Dvs};
+use chucksum::save_file::{PokemonError, PokemonLocation, PokemonStorage, SaveFile};
+
+#[test]
+fn dvs_round_trip_through_packed_bytes() {
+    let dvs = Dvs::from_bytes([0xA7, 0x3E]);
+    assert_eq!(dvs, Dvs { attack: 10, defense: 7, speed: 3, special: 14 });
+    assert_eq!(dvs.to_bytes(), [0xA7, 0x3E]);
+    assert_eq!(dvs.hp(), 0b0110);
+}
+
+#[test]
+fn setting_hp_dv_adjusts_low_bits() {
+    let mut dvs = Dvs { attack: 10, defense: 7, speed: 3, special: 14 };
+    let changes = dvs.set_hp(15);
+
+    assert_eq!(dvs.hp(), 15);
+    assert_eq!(dvs, Dvs { attack: 11, defense: 7, speed: 3, special: 15 });
+    assert_eq!(changes, vec![
+        DvChange { stat: DvStat::Attack, from: 10, to: 11 },
+        DvChange { stat: DvStat::Special, from: 14, to: 15 },
+    ]);
+    assert!(dvs.set_hp(15).is_empty());
+}
+
+#[test]
+fn pokemon_dv_setters_validate_range() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut pokemon = save_file.get_party_pokemon_data()?[0].clone();
+
+    let too_high = Dvs { attack: 16, defense: 0, speed: 0, special: 0 };
+    assert!(matches!(pokemon.set_dvs(too_high), Err(ChucksumError::Pokemon(PokemonError::InvalidDv))));
+    assert!(matches!(pokemon.set_hp_dv(16), Err(ChucksumError::Pokemon(PokemonError::InvalidDv))));
+
+    pokemon.set_dvs(Dvs { attack: 15, defense: 15, speed: 15, special: 15 })?;
+    assert_eq!(pokemon.hp_dv(), 15);
+    let changes = pokemon.set_hp_dv(0)?;
+    assert_eq!(changes.len(), 4);
+    assert_eq!(pokemon.dvs(), Dvs { attack: 14, defense: 14, speed: 14, special: 14 });
+    Ok(())
+}
+
+#[test]
+fn dvs_are_written_to_the_save() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let dvs = Dvs { attack: 12, defense: 5, speed: 9, special: 0 };
+
+    let party = PokemonLocation { storage: PokemonStorage::Party, slot: 2 };
+    save_file.set_dvs(party, dvs)?;
+    assert_eq!(save_file.get_party_pokemon_data()?[1].dvs(), dvs);
+
+    let in_box = PokemonLocation { storage: PokemonStorage::PcBox(2), slot: 1 };
+    save_file.set_dvs(in_box, dvs)?;
+    let stored = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?;
+    assert!(stored.iter().any(|p| p.location == in_box && p.pokemon.dvs() == dvs));
+
+    let empty = PokemonLocation { storage: PokemonStorage::Party, slot: 6 };
+    assert!(matches!(save_file.set_dvs(empty, dvs), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    Ok(())
+}
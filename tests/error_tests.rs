@@ -0,0 +1,46 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{BagError, ItemStorage, SaveFile};
+
+#[test]
+fn truncated_file_is_rejected() {
+    let path = std::env::temp_dir().join("chucksum_truncated.sav");
+    std::fs::write(&path, [0u8; 0x1000]).unwrap();
+
+    let result = SaveFile::new(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(result, Err(ChucksumError::InvalidFileSize { len: 0x1000, expected: offsets::SAVE_FILE_SIZE })));
+}
+
+#[test]
+fn missing_file_is_io_error() {
+    assert!(matches!(SaveFile::new("does not exist.sav"), Err(ChucksumError::Io(_))));
+}
+
+#[test]
+fn out_of_bounds_access_returns_error() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let end = save_file.len();
+
+    assert!(matches!(save_file.read_byte(end), Err(ChucksumError::OutOfBounds { offset, len: 1, .. }) if offset == end));
+    assert!(matches!(save_file.read_u16_be(end - 1), Err(ChucksumError::OutOfBounds { .. })));
+    assert!(matches!(save_file.write_bytes(end - 1, &[1, 2]), Err(ChucksumError::OutOfBounds { .. })));
+
+    // Failed writes leave the data untouched
+    assert_eq!(save_file.read_byte(end - 1)?, SaveFile::new("pokemon blue.sav")?.read_byte(end - 1)?);
+    Ok(())
+}
+
+#[test]
+fn full_bag_is_reported_through_unified_error() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.write_byte(offsets::BAG_OFFSET, offsets::MAX_BOX_ITEMS as u8)?;
+
+    let result = save_file.add_item(ItemStorage::Bag, 0x01, 1);
+    assert!(matches!(result, Err(ChucksumError::Bag(BagError::BagFull))));
+
+    let error: Box<dyn std::error::Error> = Box::new(result.unwrap_err());
+    assert!(error.source().is_some());
+    Ok(())
+}
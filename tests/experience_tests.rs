@@ -0,0 +1,58 @@
+use chucksum::error::ChucksumError;
+use chucksum::experience::{exp_for_level, exp_to_next_level, level_for_exp};
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+use chucksum::species::GrowthRate;
+
+#[test]
+fn curves_match_known_values() {
+    assert_eq!(exp_for_level(GrowthRate::MediumFast, 100), 1_000_000);
+    assert_eq!(exp_for_level(GrowthRate::MediumSlow, 100), 1_059_860);
+    assert_eq!(exp_for_level(GrowthRate::Fast, 100), 800_000);
+    assert_eq!(exp_for_level(GrowthRate::Slow, 100), 1_250_000);
+
+    assert_eq!(exp_for_level(GrowthRate::MediumSlow, 2), 9);
+    assert_eq!(exp_for_level(GrowthRate::MediumSlow, 1), 0);
+    assert_eq!(exp_for_level(GrowthRate::Slow, 5), 156);
+    assert_eq!(exp_for_level(GrowthRate::Fast, 5), 100);
+}
+
+#[test]
+fn levels_are_found_from_experience() {
+    assert_eq!(level_for_exp(GrowthRate::MediumFast, 0), 1);
+    assert_eq!(level_for_exp(GrowthRate::MediumFast, 999_999), 99);
+    assert_eq!(level_for_exp(GrowthRate::MediumFast, 1_000_000), 100);
+    assert_eq!(level_for_exp(GrowthRate::MediumFast, u32::MAX), 100);
+
+    assert_eq!(exp_to_next_level(GrowthRate::MediumFast, 8), 19);
+    assert_eq!(exp_to_next_level(GrowthRate::MediumFast, 1_000_000), 0);
+}
+
+#[test]
+fn bundled_pokemon_levels_match_experience() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    for stored in save_file.get_stored_pokemon(PokemonStorage::Party)? {
+        assert!(stored.pokemon.level_matches_experience(), "{}", stored.location);
+    }
+    Ok(())
+}
+
+#[test]
+fn level_and_experience_are_set_together() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut lapras = save_file.get_party_pokemon_data()?[0].clone();
+
+    lapras.set_level(60)?;
+    assert_eq!(lapras.experience_pts, 270_000);
+    assert!(lapras.level_matches_experience());
+    assert_eq!(lapras.exp_to_next_level()?, exp_for_level(GrowthRate::Slow, 61) - 270_000);
+
+    lapras.set_experience(2_000_000)?;
+    assert_eq!((lapras.level, lapras.experience_pts), (100, 1_250_000));
+
+    lapras.level = 99;
+    assert!(!lapras.level_matches_experience());
+
+    assert!(matches!(lapras.set_level(0), Err(ChucksumError::Pokemon(PokemonError::InvalidLevel))));
+    assert!(matches!(lapras.set_level(101), Err(ChucksumError::Pokemon(PokemonError::InvalidLevel))));
+    Ok(())
+}
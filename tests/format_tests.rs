@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use chucksum::error::ChucksumError;
+use chucksum::format::SaveContainer;
+use chucksum::offsets;
+use chucksum::save_file::SaveFile;
+
+fn write_temp(name: &str, data: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("chucksum_format_{name}"));
+    std::fs::write(&path, data).unwrap();
+    path
+}
+
+fn blue_sram() -> Vec<u8> {
+    std::fs::read("pokemon blue.sav").unwrap()
+}
+
+#[test]
+fn plain_sram_image_is_raw() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    assert_eq!(save_file.container(), &SaveContainer::Raw);
+    Ok(())
+}
+
+#[test]
+fn padded_dump_is_normalized_and_written_back_padded() -> Result<(), ChucksumError> {
+    let mut data = blue_sram();
+    data.resize(0x20000, 0xFF);
+    let path = write_temp("padded.sav", &data);
+
+    let mut save_file = SaveFile::new(path.to_str().unwrap())?;
+    assert_eq!(save_file.len(), offsets::SAVE_FILE_SIZE);
+    assert_eq!(save_file.container(), &SaveContainer::Padded { len: 0x20000, fill: 0xFF });
+    assert_eq!(save_file.get_player_name()?, SaveFile::new("pokemon blue.sav")?.get_player_name()?);
+
+    save_file.save(path.to_str().unwrap())?;
+    let written = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(written, data);
+    Ok(())
+}
+
+#[test]
+fn emulator_footer_is_preserved() -> Result<(), ChucksumError> {
+    let footer: Vec<u8> = (0..48).collect();
+    let mut data = blue_sram();
+    data.extend_from_slice(&footer);
+    let path = write_temp("footer.sav", &data);
+
+    let mut save_file = SaveFile::new(path.to_str().unwrap())?;
+    assert_eq!(save_file.container(), &SaveContainer::Footer(footer));
+
+    save_file.save(path.to_str().unwrap())?;
+    let written = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(written, data);
+    Ok(())
+}
+
+#[test]
+fn wrong_inputs_are_rejected() {
+    let blank = [0xFFu8; offsets::SAVE_FILE_SIZE];
+    assert!(matches!(SaveContainer::detect(&blank), Err(ChucksumError::UnrecognizedSave { .. })));
+    assert!(matches!(SaveContainer::detect(&[]), Err(ChucksumError::UnrecognizedSave { len: 0, .. })));
+    assert!(matches!(SaveContainer::detect(&[0x12; 0x2000]), Err(ChucksumError::InvalidFileSize { .. })));
+
+    // 64 KiB with real data in the upper half looks like a GBA flash save
+    let mut gba: Vec<u8> = blue_sram();
+    gba.extend(blue_sram());
+    assert!(matches!(SaveContainer::detect(&gba), Err(ChucksumError::UnrecognizedSave { len: 0x10000, .. })));
+
+    let mut oversized = blue_sram();
+    oversized.resize(0x9000, 0x00);
+    assert!(matches!(SaveContainer::detect(&oversized), Err(ChucksumError::UnrecognizedSave { .. })));
+}
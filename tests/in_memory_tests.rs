@@ -0,0 +1,53 @@
+use std::io::Cursor;
+
+use chucksum::error::ChucksumError;
+use chucksum::format::SaveContainer;
+use chucksum::save_file::SaveFile;
+
+fn blue_bytes() -> Vec<u8> {
+    std::fs::read("pokemon blue.sav").unwrap()
+}
+
+#[test]
+fn constructors_agree_with_loading_from_disk() -> Result<(), ChucksumError> {
+    let from_disk = SaveFile::new("pokemon blue.sav")?;
+    let bytes = blue_bytes();
+
+    for save_file in [
+        SaveFile::from_bytes(bytes.clone())?,
+        SaveFile::from_slice(&bytes)?,
+        SaveFile::from_reader(Cursor::new(bytes.clone()))?,
+    ] {
+        assert_eq!(save_file._as_slice(), from_disk._as_slice());
+        assert_eq!(save_file.get_player_name()?, from_disk.get_player_name()?);
+    }
+    Ok(())
+}
+
+#[test]
+fn to_bytes_updates_checksums() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::from_bytes(blue_bytes())?;
+    save_file.set_money(55_555)?;
+    assert!(!save_file.verify_checksums()?.is_valid());
+
+    let bytes = save_file.to_bytes()?;
+    let reloaded = SaveFile::from_bytes(bytes)?;
+
+    assert!(reloaded.verify_checksums()?.is_valid());
+    assert_eq!(reloaded.get_money()?, 55_555);
+    Ok(())
+}
+
+#[test]
+fn write_to_keeps_container_shape() -> Result<(), ChucksumError> {
+    let mut padded = blue_bytes();
+    padded.resize(0x10000, 0x00);
+
+    let mut save_file = SaveFile::from_bytes(padded.clone())?;
+    assert_eq!(save_file.container(), &SaveContainer::Padded { len: 0x10000, fill: 0x00 });
+
+    let mut output = Vec::new();
+    save_file.write_to(&mut output)?;
+    assert_eq!(output, padded);
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use chucksum::checksum::ChecksumRegion;
+use chucksum::error::ChucksumError;
+use chucksum::journal::{Checkpoint, SaveField};
+use chucksum::offsets;
+use chucksum::save_file::{ItemStorage, PokemonError, PokemonLocation, PokemonStorage, SaveFile};
+
+#[test]
+fn writes_are_journaled_and_can_be_undone_and_redone() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let name = save_file.get_player_name()?;
+    let money = save_file.get_money()?;
+
+    save_file.set_player_name("GARY")?;
+    save_file.set_money(1234)?;
+    assert_eq!(save_file.journal().len(), 2);
+    assert_eq!(save_file.journal().entries()[0].offset, offsets::PLAYER_NAME_OFFSET);
+
+    assert!(save_file.undo()?);
+    assert_eq!(save_file.get_money()?, money);
+    assert_eq!(save_file.get_player_name()?, "GARY");
+
+    assert!(save_file.redo()?);
+    assert_eq!(save_file.get_money()?, 1234);
+
+    assert!(save_file.undo()?);
+    assert!(save_file.undo()?);
+    assert!(!save_file.undo()?);
+    assert_eq!(save_file.get_player_name()?, name);
+
+    // A new write drops whatever could have been redone
+    save_file.set_money(99)?;
+    assert!(!save_file.redo()?);
+    Ok(())
+}
+
+#[test]
+fn rollback_restores_checkpoint() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let original = save_file.to_bytes()?;
+
+    save_file.set_rival_name("BLUE")?;
+    let checkpoint = save_file.checkpoint();
+    save_file.set_money(1)?;
+    save_file.add_item(ItemStorage::Bag, 0x01, 5)?;
+
+    save_file.rollback_to(checkpoint)?;
+    assert_eq!(save_file.get_rival_name()?, "BLUE");
+    assert_eq!(save_file.journal().len(), 1);
+    assert!(!save_file.redo()?);
+
+    save_file.rollback_to(Checkpoint::LOADED)?;
+    assert_eq!(save_file.to_bytes()?, original);
+    assert!(save_file.dirty_offsets().is_empty());
+    Ok(())
+}
+
+#[test]
+fn failed_transaction_leaves_save_untouched() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let money = save_file.get_money()?;
+
+    let result = save_file.transaction(|save| {
+        save.set_money(5)?;
+        save.write_byte(offsets::SAVE_FILE_SIZE, 0)
+    });
+
+    assert!(matches!(result, Err(ChucksumError::OutOfBounds { .. })));
+    assert_eq!(save_file.get_money()?, money);
+    assert!(save_file.journal().is_empty());
+
+    let result: Result<(), ChucksumError> = save_file.transaction(|save| {
+        save.set_money(5)?;
+        Err(PokemonError::InvalidData.into())
+    });
+    assert!(result.is_err());
+    assert_eq!(save_file.get_money()?, money);
+    Ok(())
+}
+
+#[test]
+fn dirty_fields_name_what_changed() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let money = save_file.get_money()?;
+
+    save_file.set_money(money + 1)?;
+    save_file.write_byte(offsets::PARTY_FIRST_PKMN + offsets::PARTY_NEXT_PKMN + offsets::PARTY_LEVEL, 50)?;
+    save_file.copy_party_pokemon(1, 1)?;
+
+    let fields = save_file.dirty_fields()?;
+    let box_count = save_file.get_box_pokemon_count(1)?;
+    assert_eq!(fields[0], SaveField::Money);
+    assert!(fields.contains(&SaveField::Pokemon(PokemonLocation { storage: PokemonStorage::Party, slot: 2 })));
+    assert!(fields.contains(&SaveField::PokemonList(PokemonStorage::PcBox(1))));
+    assert!(fields.contains(&SaveField::Pokemon(PokemonLocation { storage: PokemonStorage::PcBox(1), slot: box_count })));
+
+    // Setting a value back to what was loaded makes it clean again
+    save_file.set_money(money)?;
+    assert!(!save_file.dirty_fields()?.contains(&SaveField::Money));
+
+    // Saving updates checksums without adding to the journal
+    let entries = save_file.journal().len();
+    save_file.to_bytes()?;
+    assert_eq!(save_file.journal().len(), entries);
+    Ok(())
+}
+
+#[test]
+fn multi_write_edits_are_undone_as_one() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let original = save_file.to_bytes()?;
+
+    save_file.swap_pokemon(PokemonStorage::Party, 1, 2)?;
+    save_file.withdraw_box_pokemon(2, 1)?;
+    save_file.add_item(ItemStorage::PcBox, 0x01, 1)?;
+    assert_eq!(save_file.journal().edit_count(), 3);
+    let edited = save_file.to_bytes()?;
+
+    assert!(save_file.undo()?);
+    assert_eq!(save_file.get_items(ItemStorage::PcBox)?.len(), save_file.box_items_count()? as usize);
+    assert!(save_file.undo()?);
+    assert_eq!(save_file.validate()?, vec![]);
+    assert!(save_file.undo()?);
+    assert!(!save_file.undo()?);
+    assert_eq!(save_file.to_bytes()?, original);
+
+    while save_file.redo()? {}
+    assert_eq!(save_file.to_bytes()?, edited);
+    Ok(())
+}
+
+#[test]
+fn nested_transactions_are_one_edit() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let money = save_file.get_money()?;
+
+    save_file.transaction(|save| {
+        save.set_money(1)?;
+        let _ = save.transaction(|save| {
+            save.set_rival_name("BLUE")?;
+            save.write_byte(offsets::SAVE_FILE_SIZE, 0)
+        });
+        save.set_player_name("RED")
+    })?;
+    assert_eq!(save_file.journal().edit_count(), 1);
+    assert_eq!(save_file.journal().len(), 2);
+
+    assert!(save_file.undo()?);
+    assert_eq!(save_file.get_money()?, money);
+    assert!(save_file.journal().is_empty());
+    Ok(())
+}
+
+#[test]
+fn checksum_writes_are_never_journaled() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let main_checksum = ChecksumRegion::Main.checksum_offset();
+    let stored = save_file.read_byte(main_checksum)?;
+    save_file.write_byte(main_checksum, stored.wrapping_add(1))?;
+
+    save_file.repair()?;
+    assert_eq!(save_file.read_byte(main_checksum)?, stored);
+    assert_eq!(save_file.journal().len(), 1);
+    assert!(save_file.dirty_fields()?.is_empty());
+    Ok(())
+}
@@ -11,50 +11,51 @@ use chucksum::save_file::SaveFile;
 use chucksum::pokemon::Pokemon;
 use chucksum::pokemon::StatusCondtion;
 use chucksum::save_file::ItemStorage;
+use chucksum::error::ChucksumError;
 
 
 #[test]
-fn manual_testing() -> std::io::Result<()> {
+fn manual_testing() -> Result<(), ChucksumError> {
     run_manual_testing()
 }
 
-fn run_manual_testing() -> std::io::Result<()> {
+fn run_manual_testing() -> Result<(), ChucksumError> {
     
     let mut save_file = SaveFile::new("data.srm")?;
     
     println!("Read {} bytes", save_file.len());
     
     let offset = 0x3000;
-    let value = save_file.read_byte(offset);
+    let value = save_file.read_byte(offset)?;
     println!("0x{:04X}: 0x{:02X}", offset, value);
     
     //save_file.write_byte(offset, 66);
     
     
-    let value = save_file.read_byte(offset);
+    let value = save_file.read_byte(offset)?;
     println!("Afte write byte:");
     println!("0x{:04X}: 0x{:02X}", offset, value);
     println!();
     
-    let player_name = save_file.read_string(0x2598, 0x50);
+    let player_name = save_file.read_string(0x2598, 0x50)?;
     println!("Player Name: {player_name}");
     
     //save_file.write_string("Jerome", 0x2598, 0x50);
-    save_file.set_player_name("Jerome");
-    let player_name = save_file.get_player_name();
+    save_file.set_player_name("Jerome")?;
+    let player_name = save_file.get_player_name()?;
     println!("Player Name: {player_name}");
     
-    let rival_name = save_file.get_rival_name();
+    let rival_name = save_file.get_rival_name()?;
     println!("Rival Name: {rival_name}");
-    save_file.set_rival_name("ASSHAT");
+    save_file.set_rival_name("ASSHAT")?;
     
     
-    let rival_name = save_file.get_rival_name();
+    let rival_name = save_file.get_rival_name()?;
     println!("Rival Name: {rival_name}");
     
     
     
-    println!{"# of bag items: {}", save_file.bag_items_count()};
+    println!{"# of bag items: {}", save_file.bag_items_count()?};
     
     // Add 10 Pokeballs to first empty bag slot:
     match save_file.add_item(ItemStorage::Bag, 0x01, 10) {
@@ -67,27 +68,27 @@ fn run_manual_testing() -> std::io::Result<()> {
         Err(e) => println!("Failed to add item: {e}"),
     }
     
-    println!{"# of bag items: {}", save_file.bag_items_count()};
+    println!{"# of bag items: {}", save_file.bag_items_count()?};
     
     println!("Try listing bag items:\n\n");
-    println!("{}", save_file.list_items(ItemStorage::Bag));
+    println!("{}", save_file.list_items(ItemStorage::Bag)?);
 
     println!("Try printing box items: ");
-    println!("{}", save_file.list_items(ItemStorage::PcBox));
+    println!("{}", save_file.list_items(ItemStorage::PcBox)?);
 
     match save_file.add_item(ItemStorage::PcBox, 0x01, 98) {
         Ok(_) => println!("Added item to box successfully."),
         Err(e) => println!("Failed to add item: {e}")
     }
     
-    println!("{}", save_file.list_items(ItemStorage::PcBox));
+    println!("{}", save_file.list_items(ItemStorage::PcBox)?);
 
 
 
     
-    println!("Money: ${}", save_file.get_money().to_formatted_string(&Locale::en));
+    println!("Money: ${}", save_file.get_money()?.to_formatted_string(&Locale::en));
 
-    save_file.set_money(986_186);
+    save_file.set_money(986_186)?;
 
     // Print out party species names.
     match save_file.get_party_species_names() {
@@ -124,11 +125,11 @@ fn run_manual_testing() -> std::io::Result<()> {
         Err(e) => println!("Error: {e}")
     }
 
-    println!("Player ID: {}", save_file.get_player_id());
+    println!("Player ID: {}", save_file.get_player_id()?);
 
 
     for index in 1..13 {
-        println!("Box {index} count: {}", save_file.get_box_pokemon_count(index));
+        println!("Box {index} count: {}", save_file.get_box_pokemon_count(index)?);
     }
 
     println!("\nAfter write...\n");
@@ -154,7 +155,7 @@ fn run_manual_testing() -> std::io::Result<()> {
     }
 
     for index in 1..13 {
-        println!("Box {index} count: {}", save_file.get_box_pokemon_count(index));
+        println!("Box {index} count: {}", save_file.get_box_pokemon_count(index)?);
     }
 
     // match save_file.get_box_pokemon_data(6) {
@@ -170,7 +171,7 @@ fn run_manual_testing() -> std::io::Result<()> {
     save_file.save("pokemon red.sav")?;
 
     
-    println!("Current Box: {}", save_file.get_current_box());
+    println!("Current Box: {}", save_file.get_current_box()?);
     
     Ok(())
 }
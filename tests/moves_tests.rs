@@ -0,0 +1,49 @@
+use chucksum::moves::{self, Move, MoveEffect};
+use chucksum::pokemon::Pokemon;
+
+#[test]
+fn table_covers_every_move() {
+    let all = moves::all();
+    assert_eq!(all.len(), 165);
+    assert!(all.iter().enumerate().all(|(i, m)| m.id as usize == i + 1));
+    assert!(all.iter().all(|m| m.pp > 0 && m.pp <= 40 && m.pp % 5 == 0 && m.accuracy <= 100));
+    assert!(moves::by_id(0).is_none());
+    assert!(moves::by_id(166).is_none());
+}
+
+#[test]
+fn move_data_matches_the_game() {
+    let thunderbolt = moves::by_id(85).unwrap();
+    assert_eq!((thunderbolt.name, thunderbolt.power, thunderbolt.accuracy, thunderbolt.pp), ("Thunderbolt", 95, 100, 15));
+    assert_eq!(thunderbolt.effect, MoveEffect::Paralyze);
+
+    assert_eq!(moves::by_id(5).unwrap().name, "Mega Punch");
+    assert_eq!(moves::by_id(6).unwrap().name, "Pay Day");
+    assert_eq!(moves::by_id(63).unwrap().effect, MoveEffect::Recharge);
+    assert_eq!(moves::by_id(165).unwrap().name, "Struggle");
+}
+
+#[test]
+fn moves_parse_from_names_and_ids() {
+    assert_eq!("Thunderbolt".parse::<Move>().unwrap().id(), 85);
+    assert_eq!("thunder bolt".parse::<Move>().unwrap().id(), 85);
+    assert_eq!("double-edge".parse::<Move>().unwrap().id(), 38);
+    assert_eq!("softboiled".parse::<Move>().unwrap().id(), 135);
+    assert_eq!("57".parse::<Move>().unwrap().name(), "Surf");
+    assert_eq!(u8::from("Psychic".parse::<Move>().unwrap()), 94);
+
+    let error = "Splashh".parse::<Move>().unwrap_err();
+    assert_eq!(error.input, "Splashh");
+    assert!("0".parse::<Move>().is_err());
+    assert!("166".parse::<Move>().is_err());
+}
+
+#[test]
+fn move_names_come_from_the_table() {
+    assert_eq!(Pokemon::get_move_name(0), "<None>");
+    assert_eq!(Pokemon::get_move_name(5), "Mega Punch");
+    assert_eq!(Pokemon::get_move_name(6), "Pay Day");
+    assert!(Pokemon::is_valid_move(165));
+    assert!(!Pokemon::is_valid_move(166));
+    assert_eq!(Move::from_id(85).map(|m| m.to_string()), Some("Thunderbolt".to_string()));
+}
@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use chucksum::error::ChucksumError;
+use chucksum::persist::{self, SaveOptions};
+use chucksum::save_file::SaveFile;
+
+// Copies the bundled blue save into a fresh directory so tests don't touch each other's files.
+fn temp_copy(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("chucksum_persist_{name}"));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("blue.sav");
+    std::fs::copy("pokemon blue.sav", &path).unwrap();
+    path
+}
+
+#[test]
+fn save_replaces_file_without_leaving_temp_files() -> Result<(), ChucksumError> {
+    let path = temp_copy("atomic");
+    let filename = path.to_str().unwrap();
+
+    let mut save_file = SaveFile::new(filename)?;
+    save_file.set_money(4321)?;
+    save_file.save(filename)?;
+
+    // Saving again is fine since the file now holds what we wrote
+    save_file.set_money(1234)?;
+    save_file.save(filename)?;
+
+    assert_eq!(SaveFile::new(filename)?.get_money()?, 1234);
+    assert_eq!(std::fs::read_dir(path.parent().unwrap())?.count(), 1);
+    Ok(())
+}
+
+#[test]
+fn temp_file_of_another_writer_is_left_alone() -> Result<(), ChucksumError> {
+    let path = temp_copy("temp_name");
+    let filename = path.to_str().unwrap();
+    let other_temp = path.with_file_name("blue.sav.tmp");
+    std::fs::write(&other_temp, b"another writer")?;
+
+    let mut save_file = SaveFile::new(filename)?;
+    save_file.set_money(4321)?;
+    save_file.save(filename)?;
+
+    assert_eq!(std::fs::read(&other_temp)?, b"another writer");
+    assert_eq!(std::fs::read_dir(path.parent().unwrap())?.count(), 2);
+    Ok(())
+}
+
+#[test]
+fn external_modification_is_detected() -> Result<(), ChucksumError> {
+    let path = temp_copy("modified");
+    let filename = path.to_str().unwrap();
+
+    let mut save_file = SaveFile::new(filename)?;
+
+    // Simulate the emulator writing the file after we loaded it
+    let mut emulator = SaveFile::new(filename)?;
+    emulator.set_money(777)?;
+    emulator.save(filename)?;
+
+    save_file.set_money(1)?;
+    assert!(matches!(save_file.save(filename), Err(ChucksumError::ModifiedOnDisk { .. })));
+    assert_eq!(SaveFile::new(filename)?.get_money()?, 777);
+
+    save_file.save_with(filename, &SaveOptions { force: true, ..Default::default() })?;
+    assert_eq!(SaveFile::new(filename)?.get_money()?, 1);
+    Ok(())
+}
+
+#[test]
+fn deleted_file_is_reported_as_missing() -> Result<(), ChucksumError> {
+    let path = temp_copy("missing");
+    let filename = path.to_str().unwrap();
+
+    let mut save_file = SaveFile::new(filename)?;
+    std::fs::remove_file(&path)?;
+
+    assert!(matches!(save_file.save(filename), Err(ChucksumError::MissingOnDisk { .. })));
+    assert!(!path.exists());
+
+    save_file.save_with(filename, &SaveOptions { force: true, ..Default::default() })?;
+    assert!(path.exists());
+    Ok(())
+}
+
+#[test]
+fn saving_a_copy_keeps_checking_the_original() -> Result<(), ChucksumError> {
+    let path = temp_copy("copy");
+    let filename = path.to_str().unwrap();
+    let copy = path.with_file_name("copy.sav");
+
+    let mut save_file = SaveFile::new(filename)?;
+    save_file.set_money(1)?;
+    save_file.save(copy.to_str().unwrap())?;
+
+    let mut emulator = SaveFile::new(filename)?;
+    emulator.set_money(777)?;
+    emulator.save(filename)?;
+
+    assert!(matches!(save_file.save(filename), Err(ChucksumError::ModifiedOnDisk { .. })));
+    assert_eq!(SaveFile::new(filename)?.get_money()?, 777);
+    Ok(())
+}
+
+#[test]
+fn backups_rotate_and_keep_requested_count() -> Result<(), ChucksumError> {
+    let path = temp_copy("backups");
+    let filename = path.to_str().unwrap();
+    let options = SaveOptions { backups: 2, ..Default::default() };
+
+    let mut save_file = SaveFile::new(filename)?;
+    for money in [100, 200, 300] {
+        save_file.set_money(money)?;
+        save_file.save_with(filename, &options)?;
+    }
+
+    let newest = persist::backup_path(&path, 1);
+    let oldest = persist::backup_path(&path, 2);
+    assert_eq!(SaveFile::new(newest.to_str().unwrap())?.get_money()?, 200);
+    assert_eq!(SaveFile::new(oldest.to_str().unwrap())?.get_money()?, 100);
+    assert!(!persist::backup_path(&path, 3).exists());
+    Ok(())
+}
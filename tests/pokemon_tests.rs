@@ -6,6 +6,7 @@ use chucksum::pokemon::Pokemon;
 use chucksum::pokemon::PokemonRaw;
 use chucksum::offsets;
 use chucksum::save_file::SaveFile;
+use chucksum::error::ChucksumError;
 use chucksum::pokemon::StatusCondtion;
 #[test]
 fn iv_nibbles_are_split_correctly() {
@@ -54,7 +55,7 @@ fn _print_pokemon_list(pokemon_list: &Vec<Pokemon>) {
 }
 
 #[test]
-fn copy_party_pokemon_to_empty_box() -> std::io::Result<()> {
+fn copy_party_pokemon_to_empty_box() -> Result<(), ChucksumError> {
     
     let mut save_file = SaveFile::new("testing.srm")?;
     const BOX_NUMBER: usize = 8;
@@ -122,7 +123,7 @@ fn get_type_name_returns_correct_name() {
     assert_eq!(Pokemon::get_type_name(3), "Poison");
     assert_eq!(Pokemon::get_type_name(4), "Ground");
     assert_eq!(Pokemon::get_type_name(5), "Rock");
-    assert_eq!(Pokemon::get_type_name(6), "Invalid/Unknown");
+    assert_eq!(Pokemon::get_type_name(6), "Bird");
     assert_eq!(Pokemon::get_type_name(7), "Bug");
     assert_eq!(Pokemon::get_type_name(8), "Ghost");
     assert_eq!(Pokemon::get_type_name(20), "Fire");
@@ -155,16 +156,16 @@ fn example_pokemon_raw() -> Pokemon {
     bytes[offsets::PARTY_EXPERIENCE_PTS] = 0x00;
     bytes[offsets::PARTY_EXPERIENCE_PTS + 1] = 0x10;
     bytes[offsets::PARTY_EXPERIENCE_PTS + 2] = 0x20;
-    bytes[offsets::PARTY_HP_STAT_EXP] = 0x34; // Little-endian
-    bytes[offsets::PARTY_HP_STAT_EXP + 1] = 0x12;
-    bytes[offsets::PARTY_ATTACK_STAT_EXP] = 0x78;
-    bytes[offsets::PARTY_ATTACK_STAT_EXP + 1] = 0x56;
-    bytes[offsets::PARTY_DEFENSE_STAT_EXP] = 0xBC;
-    bytes[offsets::PARTY_DEFENSE_STAT_EXP + 1] = 0x9A;
-    bytes[offsets::PARTY_SPEED_STAT_EXP] = 0xF0;
-    bytes[offsets::PARTY_SPEED_STAT_EXP + 1] = 0xDE;
-    bytes[offsets::PARTY_SPECIAL_STAT_EXP] = 0x44;
-    bytes[offsets::PARTY_SPECIAL_STAT_EXP + 1] = 0x33;
+    bytes[offsets::PARTY_HP_STAT_EXP] = 0x12; // Big-endian
+    bytes[offsets::PARTY_HP_STAT_EXP + 1] = 0x34;
+    bytes[offsets::PARTY_ATTACK_STAT_EXP] = 0x56;
+    bytes[offsets::PARTY_ATTACK_STAT_EXP + 1] = 0x78;
+    bytes[offsets::PARTY_DEFENSE_STAT_EXP] = 0x9A;
+    bytes[offsets::PARTY_DEFENSE_STAT_EXP + 1] = 0xBC;
+    bytes[offsets::PARTY_SPEED_STAT_EXP] = 0xDE;
+    bytes[offsets::PARTY_SPEED_STAT_EXP + 1] = 0xF0;
+    bytes[offsets::PARTY_SPECIAL_STAT_EXP] = 0x33;
+    bytes[offsets::PARTY_SPECIAL_STAT_EXP + 1] = 0x44;
     bytes[offsets::PARTY_IV_1] = 0xAB;
     bytes[offsets::PARTY_IV_2] = 0xCD;
     bytes[offsets::PARTY_MAX_HP] = 0x12;
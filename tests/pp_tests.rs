@@ -0,0 +1,76 @@
+use chucksum::error::ChucksumError;
+use chucksum::moves;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn max_pp_follows_pp_ups() {
+    assert_eq!(moves::max_pp(15, 0), 15);
+    assert_eq!(moves::max_pp(15, 3), 24);
+    assert_eq!(moves::max_pp(35, 1), 42);
+    assert_eq!(moves::max_pp(40, 1), 47);
+    assert_eq!(moves::max_pp(40, 2), 54);
+    assert_eq!(moves::max_pp(40, 3), 61);
+    assert_eq!(moves::max_pp(5, 9), 8);
+}
+
+#[test]
+fn bundled_saves_have_pp_within_limits() -> Result<(), ChucksumError> {
+    for filename in ["data.srm", "pokemon blue.sav"] {
+        let save_file = SaveFile::new(filename)?;
+        let mut stored = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+        for box_number in 1..=12 {
+            stored.extend(save_file.get_stored_pokemon(PokemonStorage::PcBox(box_number))?);
+        }
+
+        for p in stored {
+            for slot in 1..=4 {
+                assert!(p.pokemon.current_pp(slot)? <= p.pokemon.max_pp(slot)?, "{} move {slot}", p.location);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn set_move_initializes_pp() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut pokemon = save_file.get_party_pokemon_data()?[0].clone();
+
+    pokemon.set_move(4, 85)?;
+    assert_eq!(pokemon.move_index4, 85);
+    assert_eq!((pokemon.current_pp(4)?, pokemon.pp_ups(4)?, pokemon.max_pp(4)?), (15, 0, 15));
+
+    pokemon.set_pp_ups(4, 3)?;
+    assert_eq!((pokemon.current_pp(4)?, pokemon.max_pp(4)?), (15, 24));
+    pokemon.restore_pp()?;
+    assert_eq!(pokemon.current_pp(4)?, 24);
+    assert_eq!(pokemon.pp[3], 0xC0 | 24);
+
+    pokemon.set_pp_ups(4, 0)?;
+    assert_eq!(pokemon.current_pp(4)?, 15);
+    pokemon.set_pp(4, 3)?;
+    assert_eq!(pokemon.current_pp(4)?, 3);
+
+    save_file.set_party_pokemon(1, &pokemon)?;
+    let written = &save_file.get_party_pokemon_data()?[0];
+    assert_eq!((written.move_index4, written.current_pp(4)?), (85, 3));
+
+    pokemon.set_move(4, 0)?;
+    assert_eq!((pokemon.move_index4, pokemon.pp[3]), (0, 0));
+    Ok(())
+}
+
+#[test]
+fn invalid_pp_edits_are_rejected() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut pokemon = save_file.get_party_pokemon_data()?[0].clone();
+    pokemon.set_move(1, 57)?;
+
+    assert!(matches!(pokemon.set_move(5, 57), Err(ChucksumError::Pokemon(PokemonError::InvalidMoveSlot))));
+    assert!(matches!(pokemon.current_pp(0), Err(ChucksumError::Pokemon(PokemonError::InvalidMoveSlot))));
+    assert!(matches!(pokemon.set_move(1, 166), Err(ChucksumError::Pokemon(PokemonError::InvalidMove))));
+    assert!(matches!(pokemon.set_pp(1, 16), Err(ChucksumError::Pokemon(PokemonError::InvalidPp))));
+    assert!(matches!(pokemon.set_pp_ups(1, 4), Err(ChucksumError::Pokemon(PokemonError::InvalidPp))));
+    assert_eq!(pokemon.current_pp(1)?, 15);
+    Ok(())
+}
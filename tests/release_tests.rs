@@ -0,0 +1,77 @@
+mod common;
+
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn released_party_pokemon_is_compacted_out() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let party = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+
+    save_file.release_party_pokemon(2)?;
+
+    let after = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+    let mut expected = common::order(&party);
+    expected.remove(1);
+    assert_eq!(common::order(&after), expected);
+    assert_eq!(after[1].pokemon, party[2].pokemon);
+    assert_eq!(after[2].pokemon, party[3].pokemon);
+
+    let list = save_file.read_bytes(offsets::PARTY_DATA_OFFSET, 5)?;
+    assert_eq!(list, &[3, party[0].pokemon.species_id, party[2].pokemon.species_id, party[3].pokemon.species_id, 0xFF]);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn released_box_pokemon_is_compacted_out() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let boxed = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?;
+
+    save_file.release_box_pokemon(2, 1)?;
+    save_file.release_box_pokemon(2, 17)?;
+
+    let after = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?;
+    assert_eq!(common::order(&after), common::order(&boxed[1..17]));
+    assert_eq!(save_file.read_byte(offsets::BOX_CURRENT_DATA_OFFSET + 17)?, offsets::PARTY_LIST_TERMINATOR);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn party_is_never_left_empty() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    for _ in 1..save_file.get_party_count()? {
+        save_file.release_party_pokemon(1)?;
+    }
+    assert_eq!(save_file.get_party_count()?, 1);
+
+    let before = save_file.to_bytes()?;
+    assert!(matches!(save_file.release_party_pokemon(1), Err(ChucksumError::Pokemon(PokemonError::LastPartyPokemon))));
+    assert!(matches!(save_file.release_party_pokemon(2), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    assert!(matches!(save_file.release_box_pokemon(2, 19), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.release_box_pokemon(0, 1), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
+
+#[test]
+fn corrupt_count_is_rejected_without_shifting() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.write_byte(offsets::PARTY_DATA_OFFSET, 0xFF)?;
+    save_file.write_byte(offsets::BOX_CURRENT_DATA_OFFSET, 0xFF)?;
+
+    let before = save_file.to_bytes()?;
+    assert!(matches!(save_file.release_party_pokemon(2), Err(ChucksumError::Pokemon(PokemonError::InvalidData))));
+    assert!(matches!(save_file.release_box_pokemon(2, 1), Err(ChucksumError::Pokemon(PokemonError::InvalidData))));
+    assert_eq!(save_file.to_bytes()?, before);
+
+    // Withdrawing appends to the party first, that has to be rolled back too
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.write_byte(offsets::BOX_CURRENT_DATA_OFFSET, 0xFF)?;
+    let before = save_file.to_bytes()?;
+    assert!(matches!(save_file.withdraw_box_pokemon(2, 1), Err(ChucksumError::Pokemon(PokemonError::InvalidData))));
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn party_pokemon_can_be_swapped() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let party = common::order(&save_file.get_stored_pokemon(PokemonStorage::Party)?);
+
+    save_file.swap_pokemon(PokemonStorage::Party, 1, 3)?;
+
+    let after = common::order(&save_file.get_stored_pokemon(PokemonStorage::Party)?);
+    assert_eq!(after, vec![party[2].clone(), party[1].clone(), party[0].clone(), party[3].clone()]);
+    assert_eq!(save_file.read_bytes(offsets::PARTY_DATA_OFFSET + 1, 4)?, after.iter().map(|p| p.0).collect::<Vec<u8>>());
+    assert_eq!(save_file.validate()?, vec![]);
+
+    save_file.swap_pokemon(PokemonStorage::Party, 2, 2)?;
+    assert_eq!(common::order(&save_file.get_stored_pokemon(PokemonStorage::Party)?), after);
+    Ok(())
+}
+
+#[test]
+fn box_pokemon_can_be_moved_to_a_slot() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let storage = PokemonStorage::PcBox(2);
+    let boxed = common::order(&save_file.get_stored_pokemon(storage)?);
+
+    save_file.move_pokemon_to_slot(storage, 2, 5)?;
+    let mut expected = boxed.clone();
+    let moved = expected.remove(1);
+    expected.insert(4, moved);
+    assert_eq!(common::order(&save_file.get_stored_pokemon(storage)?), expected);
+
+    save_file.move_pokemon_to_slot(storage, 18, 1)?;
+    let last = expected.pop().unwrap();
+    expected.insert(0, last);
+    assert_eq!(common::order(&save_file.get_stored_pokemon(storage)?), expected);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn reordering_needs_occupied_slots() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let before = save_file.to_bytes()?;
+
+    assert!(matches!(save_file.swap_pokemon(PokemonStorage::Party, 1, 5), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    assert!(matches!(save_file.move_pokemon_to_slot(PokemonStorage::PcBox(2), 0, 3), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.move_pokemon_to_slot(PokemonStorage::PcBox(2), 3, 19), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.swap_pokemon(PokemonStorage::PcBox(13), 1, 2), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use chucksum::checksum::ChecksumRegion;
+use chucksum::offsets;
+use chucksum::repair::RepairAction;
+use chucksum::error::ChucksumError;
+use chucksum::save_file::{ItemStorage, PokemonLocation, PokemonStorage, SaveFile};
+use chucksum::validator::{Diagnostic, NameField};
+
+#[test]
+fn repair_fixes_overfilled_bag() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("data.srm")?;
+    let actions = save_file.repair()?;
+
+    assert!(actions.contains(&RepairAction::ClampedItemCount { storage: ItemStorage::Bag, from: 21, to: 20 }));
+    assert_eq!(save_file.bag_items_count()?, 20);
+    assert!(save_file.validate()?.is_empty());
+    assert!(save_file.verify_checksums()?.is_valid());
+    Ok(())
+}
+
+#[test]
+fn repair_rebuilds_species_list_and_names() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let species_list = offsets::PARTY_DATA_OFFSET + offsets::PARTY_SPECIES_LIST_OFFSET;
+    let second_species = save_file.read_byte(offsets::PARTY_FIRST_PKMN + offsets::PARTY_NEXT_PKMN)?;
+
+    save_file.write_bytes(species_list, &[0x00, 0x00, 0x00, 0x00, 0x00])?;
+    save_file.write_bytes(offsets::PARTY_FIRST_NICK, &[0x80; offsets::PARTY_OT_NICK_SIZE])?;
+
+    let actions = save_file.repair()?;
+    let second = PokemonLocation { storage: PokemonStorage::Party, slot: 2 };
+
+    assert!(actions.contains(&RepairAction::RebuiltSpeciesEntry { location: second, from: 0, to: second_species }));
+    assert!(actions.contains(&RepairAction::RestoredSpeciesListTerminator { storage: PokemonStorage::Party, offset: species_list + 4 }));
+    assert!(actions.contains(&RepairAction::TerminatedName {
+        field: NameField::Nickname(PokemonLocation { storage: PokemonStorage::Party, slot: 1 }),
+        offset: offsets::PARTY_FIRST_NICK,
+    }));
+    assert!(actions.iter().any(|a| matches!(a, RepairAction::UpdatedChecksum { region: ChecksumRegion::Main, .. })));
+    assert!(save_file.validate()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn repair_clamps_box_count_and_is_idempotent() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let box_offset = SaveFile::box_data_offset(1);
+    save_file.write_byte(box_offset, 25)?;
+
+    let actions = save_file.repair()?;
+
+    assert!(actions.contains(&RepairAction::ClampedPokemonCount { storage: PokemonStorage::PcBox(1), from: 25, to: 20 }));
+    assert!(!save_file.validate()?.iter().any(|d| matches!(d, Diagnostic::PokemonCountOutOfRange { .. })));
+    assert!(save_file.verify_checksums()?.is_valid());
+    assert_eq!(save_file.repair()?, vec![]);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::pokemon::Pokemon;
+use chucksum::save_file::{PokemonStorage, SaveFile};
+use chucksum::species::{self, GrowthRate, Species};
+
+#[test]
+fn table_covers_every_index_and_dex_number() {
+    let all = species::all();
+    assert_eq!(all.len(), 190);
+    assert!(all.iter().enumerate().all(|(i, s)| s.index as usize == i + 1));
+    assert_eq!(all.iter().filter(|s| s.is_missingno()).count(), 39);
+
+    for dex_number in 1..=151 {
+        let info = species::by_dex_number(dex_number).unwrap();
+        assert_eq!(info.dex_number, dex_number);
+        assert!(info.starting_moves.iter().filter(|m| **m != 0).all(|m| Pokemon::is_valid_move(*m)), "{}", info.name);
+    }
+    assert!(species::by_dex_number(0).is_none());
+    assert!(species::by_dex_number(152).is_none());
+}
+
+#[test]
+fn lookups_agree() {
+    let pikachu = species::by_name("pikachu").unwrap();
+    assert_eq!(pikachu.index, 84);
+    assert_eq!(pikachu.dex_number, 25);
+    assert_eq!(species::by_dex_number(25), Some(pikachu));
+    assert_eq!(species::by_index(84), Some(pikachu));
+
+    let bulbasaur = species::by_index(153).unwrap();
+    assert_eq!((bulbasaur.name, bulbasaur.dex_number, bulbasaur.growth_rate), ("Bulbasaur", 1, GrowthRate::MediumSlow));
+    assert_eq!(bulbasaur.starting_moves, [33, 45, 0, 0]);
+
+    assert_eq!(species::by_name("PRIMEAPE").unwrap().index, 117);
+    assert_eq!(species::by_name("Nidoran♀").unwrap().dex_number, 29);
+    assert!(species::by_name("MissingNo").is_none());
+    assert!(species::by_index(0).is_none());
+    assert!(species::by_index(191).is_none());
+}
+
+#[test]
+fn missingno_slots_are_explicit() {
+    let missingno = species::by_index(31).unwrap();
+    assert!(missingno.is_missingno());
+    assert_eq!(missingno.name, "MissingNo");
+    assert!(!Pokemon::is_valid_species(31));
+    assert_eq!(Pokemon::get_species_name(31), "MissingNo");
+}
+
+#[test]
+fn types_match_bundled_saves() -> Result<(), ChucksumError> {
+    for filename in ["data.srm", "pokemon blue.sav"] {
+        let save_file = SaveFile::new(filename)?;
+        let storages = std::iter::once(PokemonStorage::Party)
+            .chain((1..=offsets::NUM_POKEMON_BOXES).map(PokemonStorage::PcBox));
+
+        for storage in storages {
+            for stored in save_file.get_stored_pokemon(storage)? {
+                let info = species::by_index(stored.pokemon.species_id).unwrap();
+                assert_eq!((stored.pokemon.pkmn_type_1, stored.pokemon.pkmn_type_2), (info.type_1.id(), info.type_2.id()), "{}", info.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn species_converts_between_index_and_dex_number() {
+    let bulbasaur = Species::from_dex_number(1).unwrap();
+    assert_eq!(bulbasaur.index(), 153);
+    assert_eq!(u8::from(bulbasaur), 153);
+    assert_eq!(Species::from_index(1).unwrap().dex_number(), 112);
+    assert_eq!(Species::from_index(1).unwrap().to_string(), "Rhydon");
+
+    assert!(Species::from_index(31).is_none());
+    assert!(Species::from_dex_number(0).is_none());
+    assert!(Species::from_dex_number(152).is_none());
+}
+
+#[test]
+fn species_parses_names_and_numbers() {
+    let parse = |s: &str| s.parse::<Species>().map(|species| species.dex_number());
+
+    assert_eq!(parse("nidoran♀"), Ok(29));
+    assert_eq!(parse("Nidoran F"), Ok(29));
+    assert_eq!(parse("NIDORAN-M"), Ok(32));
+    assert_eq!(parse("Farfetch'd"), Ok(83));
+    assert_eq!(parse("farfetchd"), Ok(83));
+    assert_eq!(parse("mr mime"), Ok(122));
+    assert_eq!(parse(" Mewtwo "), Ok(150));
+    assert_eq!(parse("25"), Ok(25));
+    assert_eq!(parse("#006"), Ok(6));
+
+    assert!(parse("Nidoran").is_err());
+    assert!(parse("MissingNo").is_err());
+    assert!(parse("0").is_err());
+    assert_eq!("Togepi".parse::<Species>().unwrap_err().input, "Togepi");
+}
+
+#[test]
+fn pokemon_reports_its_species() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let lapras = save_file.get_party_pokemon_data()?[0].species();
+    assert_eq!(lapras, Some("Lapras".parse().unwrap()));
+    Ok(())
+}
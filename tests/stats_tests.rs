@@ -0,0 +1,66 @@
+use chucksum::error::ChucksumError;
+use chucksum::pokemon::Pokemon;
+use chucksum::save_file::{PokemonError, SaveFile};
+use chucksum::stats::{self, Stats};
+
+#[test]
+fn hp_dv_is_built_from_low_bits() {
+    assert_eq!(stats::hp_dv(10, 11, 0, 10), 0b0100);
+    assert_eq!(stats::hp_dv(15, 15, 15, 15), 15);
+    assert_eq!(stats::hp_dv(14, 12, 8, 2), 0);
+}
+
+#[test]
+fn stat_exp_bonus_uses_rounded_up_square_root() {
+    assert_eq!(stats::stat_exp_bonus(0), 0);
+    assert_eq!(stats::stat_exp_bonus(100), 2);
+    assert_eq!(stats::stat_exp_bonus(101), 2);
+    assert_eq!(stats::stat_exp_bonus(225), 3);
+    assert_eq!(stats::stat_exp_bonus(226), 4);
+    assert_eq!(stats::stat_exp_bonus(65535), 63);
+}
+
+#[test]
+fn maxed_mewtwo_matches_known_stats() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut mewtwo = save_file.get_party_pokemon_data()?[0].clone();
+    mewtwo.species_id = 131;
+    mewtwo.level = 100;
+    (mewtwo.attack_iv, mewtwo.defense_iv, mewtwo.speed_iv, mewtwo.special_iv) = (15, 15, 15, 15);
+    mewtwo.hp_stat_exp = 65535;
+    mewtwo.attack_stat_exp = 65535;
+    mewtwo.defense_stat_exp = 65535;
+    mewtwo.speed_stat_exp = 65535;
+    mewtwo.special_stat_exp = 65535;
+
+    assert_eq!(mewtwo.calculated_stats()?, Stats { max_hp: 415, attack: 318, defense: 278, speed: 358, special: 406 });
+    Ok(())
+}
+
+#[test]
+fn bundled_party_stats_are_checked_and_fixed() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let party = save_file.get_party_pokemon_data()?;
+
+    // The Lapras gained stat experience after its stats were last calculated
+    let (mut lapras, others): (Vec<Pokemon>, Vec<Pokemon>) = party.into_iter().partition(|p| p.species_id == 19);
+    assert!(others.iter().all(Pokemon::stats_are_consistent));
+    let lapras = &mut lapras[0];
+    assert!(!lapras.stats_are_consistent());
+
+    lapras.recalculate_stats()?;
+    assert!(lapras.stats_are_consistent());
+    assert_eq!(lapras.stored_stats(), Stats { max_hp: 236, attack: 122, defense: 122, speed: 97, special: 143 });
+    Ok(())
+}
+
+#[test]
+fn unknown_species_has_no_stats() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut missingno = save_file.get_party_pokemon_data()?[0].clone();
+    missingno.species_id = 31;
+
+    assert!(matches!(missingno.recalculate_stats(), Err(ChucksumError::Pokemon(PokemonError::UnknownSpecies))));
+    assert!(!missingno.stats_are_consistent());
+    Ok(())
+}
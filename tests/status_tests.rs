@@ -0,0 +1,65 @@
+use chucksum::error::ChucksumError;
+use chucksum::pokemon::{Status, StatusCondition};
+use chucksum::save_file::{PokemonError, PokemonLocation, PokemonStorage, SaveFile};
+
+#[test]
+fn sleep_counter_is_decoded() {
+    let status = Status::from_bits_retain(0x03);
+    assert!(status.is_asleep());
+    assert_eq!(status.sleep_turns(), 3);
+    assert_eq!(status.conditions(), vec![StatusCondition::Asleep]);
+    assert_eq!(status.to_string(), "Asleep (3 turns)");
+    assert_eq!(StatusCondition::from_byte(0x03), StatusCondition::Asleep);
+    assert_eq!(StatusCondition::from_byte(0x07), StatusCondition::Asleep);
+}
+
+#[test]
+fn status_bytes_round_trip() {
+    for byte in 0..=u8::MAX {
+        assert_eq!(Status::from_bits_retain(byte).bits(), byte);
+    }
+
+    let combined = Status::from_bits_retain(0x4A);
+    assert_eq!(combined.conditions(), vec![StatusCondition::Asleep, StatusCondition::Poisoned, StatusCondition::Paralyzed]);
+    assert_eq!(combined.to_string(), "Asleep (2 turns), Poisoned, Paralyzed");
+
+    assert!(Status::from_bits_retain(0x80).is_healthy());
+    assert_eq!(Status::empty().to_string(), "None");
+    assert_eq!(StatusCondition::from_byte(0x10), StatusCondition::Burned);
+}
+
+#[test]
+fn sleep_turns_are_validated() -> Result<(), ChucksumError> {
+    assert_eq!(Status::asleep(1)?.to_string(), "Asleep (1 turn)");
+    assert_eq!(Status::asleep(7)?.bits(), 0x07);
+    for turns in 1..=7 {
+        assert!(Status::asleep(turns)?.is_asleep());
+    }
+    let combined = Status::asleep(2)? | Status::PARALYSIS;
+    assert_eq!((combined.sleep_turns(), combined.contains(Status::PARALYSIS)), (2, true));
+    assert!(matches!(Status::asleep(0), Err(ChucksumError::Pokemon(PokemonError::InvalidStatus))));
+    assert!(matches!(Status::asleep(8), Err(ChucksumError::Pokemon(PokemonError::InvalidStatus))));
+    Ok(())
+}
+
+#[test]
+fn status_is_written_to_party_pokemon() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let location = PokemonLocation { storage: PokemonStorage::Party, slot: 2 };
+
+    save_file.set_status(location, Status::PARALYSIS)?;
+    assert_eq!(save_file.get_party_pokemon_data()?[1].status(), Status::PARALYSIS);
+    save_file.set_status(location, Status::asleep(4)?)?;
+    assert_eq!(save_file.get_party_pokemon_data()?[1].status().sleep_turns(), 4);
+
+    save_file.set_status(location, Status::empty())?;
+    assert!(save_file.get_party_pokemon_data()?[1].status().is_healthy());
+
+    let mut pokemon = save_file.get_party_pokemon_data()?[0].clone();
+    pokemon.set_status(Status::BURN | Status::POISON);
+    assert_eq!(pokemon.status, 0x18);
+
+    let missing = PokemonLocation { storage: PokemonStorage::Party, slot: 5 };
+    assert!(matches!(save_file.set_status(missing, Status::BURN), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use chucksum::error::ChucksumError;
+use chucksum::moves;
+use chucksum::pokemon::Pokemon;
+use chucksum::save_file::SaveFile;
+use chucksum::species;
+use chucksum::types::{self, Effectiveness, PokemonType};
+
+#[test]
+fn type_ids_match_save_data() {
+    assert_eq!(PokemonType::from_id(6), Some(PokemonType::Bird));
+    assert_eq!(PokemonType::from_id(23), Some(PokemonType::Electric));
+    assert_eq!(PokemonType::from_id(9), None);
+    assert_eq!(PokemonType::from_id(27), None);
+    assert!(PokemonType::ALL.iter().all(|t| PokemonType::from_id(t.id()) == Some(*t)));
+    assert_eq!(u8::from(PokemonType::Dragon), 26);
+    assert_eq!(PokemonType::Psychic.to_string(), "Psychic");
+
+    assert_eq!(species::by_name("Pikachu").unwrap().type_1, PokemonType::Electric);
+    assert_eq!(moves::by_name("Bite").unwrap().move_type, PokemonType::Normal);
+}
+
+#[test]
+fn gen_1_quirks_are_kept() {
+    assert_eq!(types::effectiveness(PokemonType::Ghost, PokemonType::Psychic), Effectiveness::NoEffect);
+    assert_eq!(types::effectiveness(PokemonType::Bug, PokemonType::Poison), Effectiveness::SuperEffective);
+    assert_eq!(types::effectiveness(PokemonType::Poison, PokemonType::Bug), Effectiveness::SuperEffective);
+    assert_eq!(types::effectiveness(PokemonType::Ice, PokemonType::Fire), Effectiveness::Normal);
+    assert_eq!(types::effectiveness(PokemonType::Normal, PokemonType::Bird), Effectiveness::Normal);
+}
+
+#[test]
+fn dual_types_multiply() {
+    use PokemonType::*;
+
+    assert_eq!(types::damage_multiplier(Ice, Ground, Flying), 4.0);
+    assert_eq!(types::damage_multiplier(Ice, Water, Flying), 1.0);
+    assert_eq!(types::damage_multiplier(Ice, Dragon, Flying), 4.0);
+    assert_eq!(types::damage_multiplier(Electric, Water, Ground), 0.0);
+    assert_eq!(types::damage_multiplier(Grass, Grass, Poison), 0.25);
+    assert_eq!(types::damage_multiplier(Fire, Fire, Fire), 0.5);
+
+    // Gengar: Bug's advantage over Poison is cancelled out by Ghost resisting it.
+    let weaknesses: Vec<PokemonType> = types::weaknesses(Ghost, Poison).into_iter().map(|(t, _)| t).collect();
+    assert_eq!(weaknesses, vec![Ground, Ghost, Psychic]);
+
+    let matchups = types::matchups(Ghost, Poison);
+    assert_eq!(matchups.last(), Some(&(Fighting, 0.0)));
+}
+
+#[test]
+fn party_members_have_weaknesses() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    for pokemon in save_file.get_party_pokemon_data()? {
+        let (type_1, type_2) = pokemon.types().unwrap();
+        let info = pokemon.species().unwrap().info();
+        assert_eq!((type_1, type_2), (info.type_1, info.type_2));
+        assert!(!pokemon.weaknesses()?.is_empty(), "{}", Pokemon::get_species_name(pokemon.species_id));
+    }
+
+    let lapras = &save_file.get_party_pokemon_data()?[0];
+    assert_eq!(lapras.weaknesses()?, vec![(PokemonType::Fighting, 2.0), (PokemonType::Rock, 2.0), (PokemonType::Grass, 2.0), (PokemonType::Electric, 2.0)]);
+    Ok(())
+}
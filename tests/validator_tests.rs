@@ -0,0 +1,68 @@
+use chucksum::offsets;
+use chucksum::error::ChucksumError;
+use chucksum::save_file::{ItemStorage, PokemonLocation, PokemonStorage, SaveFile};
+use chucksum::validator::{Diagnostic, NameField};
+
+#[test]
+fn clean_save_has_no_diagnostics() -> Result<(), ChucksumError> {
+    let save_file = SaveFile::new("pokemon blue.sav")?;
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn overfilled_bag_is_reported() -> Result<(), ChucksumError> {
+    // data.srm holds 21 bag items, one more than the bag can fit.
+    let save_file = SaveFile::new("data.srm")?;
+    let diagnostics = save_file.validate()?;
+
+    assert!(diagnostics.contains(&Diagnostic::ItemCountOutOfRange { storage: ItemStorage::Bag, count: 21, max: 20 }));
+    assert!(diagnostics.contains(&Diagnostic::ItemListUnterminated { storage: ItemStorage::Bag, count: 21 }));
+    Ok(())
+}
+
+#[test]
+fn party_list_problems_are_reported() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let species_list = offsets::PARTY_DATA_OFFSET + offsets::PARTY_SPECIES_LIST_OFFSET;
+
+    // Party of 4: claim 5 members and put a MissingNo in the second slot's data.
+    save_file.write_byte(offsets::PARTY_DATA_OFFSET, 5)?;
+    save_file.write_byte(offsets::PARTY_FIRST_PKMN + offsets::PARTY_NEXT_PKMN, 31)?;
+
+    let diagnostics = save_file.validate()?;
+    let second = PokemonLocation { storage: PokemonStorage::Party, slot: 2 };
+
+    assert!(diagnostics.contains(&Diagnostic::SpeciesListLengthMismatch { storage: PokemonStorage::Party, count: 5, list_len: 4 }));
+    assert!(diagnostics.contains(&Diagnostic::SpeciesListEntryMismatch {
+        location: second,
+        list_species: save_file.read_byte(species_list + 1)?,
+        struct_species: 31,
+    }));
+    assert!(diagnostics.contains(&Diagnostic::UnknownSpecies { location: second, species_id: 31 }));
+
+    save_file.write_byte(offsets::PARTY_DATA_OFFSET, 9)?;
+    save_file.write_bytes(species_list, &[1; 7])?;
+    let diagnostics = save_file.validate()?;
+
+    assert!(diagnostics.contains(&Diagnostic::PokemonCountOutOfRange { storage: PokemonStorage::Party, count: 9, max: 6 }));
+    assert!(diagnostics.contains(&Diagnostic::SpeciesListUnterminated { storage: PokemonStorage::Party }));
+    Ok(())
+}
+
+#[test]
+fn names_moves_and_money_are_checked() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+
+    save_file.write_bytes(offsets::RIVAL_NAME_OFFSET, &[0x80; offsets::PARTY_OT_NICK_SIZE])?;
+    save_file.write_byte(offsets::PARTY_FIRST_PKMN + offsets::PARTY_MOVE_INDEX_3, 200)?;
+    save_file.write_byte(offsets::MONEY_OFFSET + 1, 0x3C)?;
+
+    let diagnostics = save_file.validate()?;
+    let first = PokemonLocation { storage: PokemonStorage::Party, slot: 1 };
+
+    assert!(diagnostics.contains(&Diagnostic::UnterminatedName { field: NameField::Rival, offset: offsets::RIVAL_NAME_OFFSET }));
+    assert!(diagnostics.contains(&Diagnostic::UnknownMove { location: first, move_slot: 3, move_id: 200 }));
+    assert!(diagnostics.contains(&Diagnostic::InvalidMoneyDigits { offset: offsets::MONEY_OFFSET + 1, byte: 0x3C }));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn withdrawn_pokemon_joins_the_party() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let boxed = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?;
+    let party_count = save_file.get_party_count()?;
+
+    save_file.withdraw_box_pokemon(2, 3)?;
+
+    let party = save_file.get_stored_pokemon(PokemonStorage::Party)?;
+    assert_eq!(party.len(), party_count + 1);
+    let withdrawn = party.last().unwrap();
+    assert_eq!(withdrawn.nickname, boxed[2].nickname);
+    assert_eq!(withdrawn.ot_name, boxed[2].ot_name);
+    assert_eq!(withdrawn.pokemon.species_id, boxed[2].pokemon.species_id);
+    assert_eq!(withdrawn.pokemon.experience_pts, boxed[2].pokemon.experience_pts);
+    assert_eq!(withdrawn.pokemon.level, boxed[2].pokemon.box_level);
+    assert_eq!(withdrawn.pokemon.box_level, boxed[2].pokemon.box_level);
+    assert!(withdrawn.pokemon.stats_are_consistent());
+    assert!(withdrawn.pokemon.current_hp <= withdrawn.pokemon.max_hp);
+    assert_eq!(save_file.read_bytes(offsets::PARTY_DATA_OFFSET + party.len() + 1, 1)?, &[offsets::PARTY_LIST_TERMINATOR]);
+
+    // The box closes up behind it
+    let after: Vec<String> = save_file.get_stored_pokemon(PokemonStorage::PcBox(2))?.into_iter().map(|p| p.nickname).collect();
+    let expected: Vec<String> = boxed.iter().enumerate().filter(|(i, _)| *i != 2).map(|(_, p)| p.nickname.clone()).collect();
+    assert_eq!(after, expected);
+    assert_eq!(save_file.read_byte(offsets::BOX_CURRENT_DATA_OFFSET + boxed.len())?, offsets::PARTY_LIST_TERMINATOR);
+
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn withdraw_can_be_rolled_back() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let before = save_file.to_bytes()?;
+    let checkpoint = save_file.checkpoint();
+
+    save_file.withdraw_box_pokemon(2, 18)?;
+    save_file.rollback_to(checkpoint)?;
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
+
+#[test]
+fn withdraw_is_refused_when_party_is_full() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    save_file.withdraw_box_pokemon(2, 1)?;
+    save_file.withdraw_box_pokemon(2, 1)?;
+    assert_eq!(save_file.get_party_count()?, offsets::MAX_PARTY_SIZE);
+
+    let before = save_file.to_bytes()?;
+    assert!(matches!(save_file.withdraw_box_pokemon(2, 1), Err(ChucksumError::Pokemon(PokemonError::PartyFull))));
+    assert!(matches!(save_file.withdraw_box_pokemon(2, 17), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.withdraw_box_pokemon(13, 1), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert_eq!(save_file.to_bytes()?, before);
+    Ok(())
+}
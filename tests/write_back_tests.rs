@@ -0,0 +1,95 @@
+use chucksum::error::ChucksumError;
+use chucksum::offsets;
+use chucksum::pokemon::Pokemon;
+use chucksum::save_file::{PokemonError, PokemonStorage, SaveFile};
+
+#[test]
+fn party_and_box_structures_round_trip() -> Result<(), ChucksumError> {
+    for filename in ["data.srm", "pokemon blue.sav"] {
+        let save_file = SaveFile::new(filename)?;
+
+        let party = save_file.pokemon_list_layout(PokemonStorage::Party)?;
+        for slot in 0..save_file.get_party_count()? {
+            let offset = party.first_pokemon + party.pokemon_size * slot;
+            let pokemon = Pokemon::from_raw(save_file.read_party_pokemon_raw(offset)?);
+            assert_eq!(pokemon.to_raw().get_for_party(), save_file.read_bytes(offset, offsets::PARTY_NEXT_PKMN)?);
+        }
+
+        for box_number in 1..=offsets::NUM_POKEMON_BOXES {
+            let layout = save_file.pokemon_list_layout(PokemonStorage::PcBox(box_number))?;
+            for slot in 0..save_file.get_box_pokemon_count(box_number)? {
+                let offset = layout.first_pokemon + layout.pokemon_size * slot;
+                let pokemon = Pokemon::from_raw(save_file.read_box_pokemon_raw(offset)?);
+                assert_eq!(pokemon.to_raw().get_for_box(), save_file.read_bytes(offset, offsets::BOX_NEXT_PKMN)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn edited_party_pokemon_is_written_back() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let mut pokemon = save_file.get_party_pokemon_data()?[1].clone();
+
+    pokemon.level = 42;
+    pokemon.move_index4 = 85;
+    pokemon.attack_iv = 15;
+    pokemon.experience_pts = 0x01_2345;
+    save_file.set_party_pokemon(2, &pokemon)?;
+
+    assert_eq!(save_file.get_party_pokemon_data()?[1], pokemon);
+    let offset = offsets::PARTY_FIRST_PKMN + offsets::PARTY_NEXT_PKMN;
+    assert_eq!(save_file.read_byte(offset + offsets::PARTY_LEVEL)?, 42);
+    assert_eq!(save_file.read_byte(offset + offsets::PARTY_BOX_LEVEL)?, pokemon.box_level);
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn edited_box_pokemon_is_written_back() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+
+    // Box 2 is the current box, so this goes to its working copy
+    for box_number in [1, 2] {
+        let mut pokemon = save_file.get_box_pokemon_data(box_number)?[0].clone();
+        pokemon.species_id = 0x54; // Pikachu
+        pokemon.level = 7;
+        save_file.set_box_pokemon(box_number, 1, &pokemon)?;
+
+        // A boxed pokemon's only level byte is the box level
+        pokemon.box_level = 7;
+        assert_eq!(save_file.get_box_pokemon_data(box_number)?[0], pokemon);
+        let species_list = save_file.get_box_pokemon_count_offset(box_number)? + 1;
+        assert_eq!(save_file.read_byte(species_list)?, 0x54);
+    }
+    assert_eq!(save_file.validate()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn empty_slots_are_refused() -> Result<(), ChucksumError> {
+    let mut save_file = SaveFile::new("pokemon blue.sav")?;
+    let pokemon = save_file.get_party_pokemon_data()?[0].clone();
+    let box_count = save_file.get_box_pokemon_count(1)?;
+
+    assert!(matches!(save_file.set_party_pokemon(5, &pokemon), Err(ChucksumError::Pokemon(PokemonError::InvalidPartySlot))));
+    assert!(matches!(save_file.set_box_pokemon(1, box_count + 1, &pokemon), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxSlot))));
+    assert!(matches!(save_file.set_box_pokemon(13, 1, &pokemon), Err(ChucksumError::Pokemon(PokemonError::InvalidBoxNumber))));
+    assert!(save_file.journal().is_empty());
+    Ok(())
+}
+
+#[test]
+fn box_level_is_synced_when_boxing() -> Result<(), ChucksumError> {
+    // The first party member in data.srm has levelled up since its box level byte was last written.
+    let mut save_file = SaveFile::new("data.srm")?;
+    let pokemon = save_file.get_party_pokemon_data()?[0].clone();
+    assert_ne!(pokemon.box_level, pokemon.level);
+    assert_eq!(pokemon.to_box_raw().get_for_box()[offsets::PARTY_BOX_LEVEL], pokemon.level);
+
+    save_file.copy_party_pokemon(1, 1)?;
+    let boxed = save_file.get_box_pokemon_data(1)?.pop().unwrap();
+    assert_eq!((boxed.level, boxed.box_level), (pokemon.level, pokemon.level));
+    Ok(())
+}